@@ -0,0 +1,195 @@
+//! Achievements unlocked by reaching [`Statistics`] milestones, announced with a toast.
+
+#[cfg(not(target_family = "wasm"))]
+use crate::game::statistics::SAVE_DIR;
+use crate::{
+    AppSystems,
+    game::{enemy::EnemyVariant, statistics::Statistics},
+    theme::widget,
+};
+use bevy::{platform::collections::HashSet, prelude::*};
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(UnlockedAchievements::load());
+
+    app.add_observer(show_unlocked_toast);
+
+    app.add_systems(
+        Update,
+        check_achievements
+            .run_if(resource_changed::<Statistics>)
+            .in_set(AppSystems::Update),
+    );
+}
+
+#[cfg(not(target_family = "wasm"))]
+const ACHIEVEMENTS_FILE: &str = "achievements.txt";
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Achievement {
+    FirstBlood,
+    Exterminator,
+    BiggerTheyAre,
+    BossSlayer,
+    ChainReaction,
+    Survivor,
+    Marathon,
+    Collector,
+    TriggerHappy,
+    NeverGiveUp,
+}
+
+impl Achievement {
+    pub const ALL: [Self; 10] = [
+        Self::FirstBlood,
+        Self::Exterminator,
+        Self::BiggerTheyAre,
+        Self::BossSlayer,
+        Self::ChainReaction,
+        Self::Survivor,
+        Self::Marathon,
+        Self::Collector,
+        Self::TriggerHappy,
+        Self::NeverGiveUp,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FirstBlood => "First Blood",
+            Self::Exterminator => "Exterminator",
+            Self::BiggerTheyAre => "The Bigger They Are",
+            Self::BossSlayer => "Boss Slayer",
+            Self::ChainReaction => "Chain Reaction",
+            Self::Survivor => "Survivor",
+            Self::Marathon => "Marathon",
+            Self::Collector => "Collector",
+            Self::TriggerHappy => "Trigger Happy",
+            Self::NeverGiveUp => "Never Give Up",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::FirstBlood => "Kill an enemy",
+            Self::Exterminator => "Kill 1000 enemies",
+            Self::BiggerTheyAre => "Kill 100 bigger enemies",
+            Self::BossSlayer => "Defeat a boss",
+            Self::ChainReaction => "Kill 5 enemies with a single shot",
+            Self::Survivor => "Survive for 5 minutes",
+            Self::Marathon => "Survive for 10 minutes",
+            Self::Collector => "Pick up your bullet 500 times",
+            Self::TriggerHappy => "Fire 1000 shots",
+            Self::NeverGiveUp => "Die 25 times",
+        }
+    }
+
+    /// Unlock condition.
+    fn is_met(&self, stats: &Statistics) -> bool {
+        match self {
+            Self::FirstBlood => stats.total_kills() >= 1,
+            Self::Exterminator => stats.total_kills() >= 1000,
+            Self::BiggerTheyAre => stats.kills_of(EnemyVariant::Bigger) >= 100,
+            Self::BossSlayer => stats.kills_of(EnemyVariant::Boss) >= 1,
+            Self::ChainReaction => stats.max_combo >= 5,
+            Self::Survivor => stats.longest_survival >= Duration::from_secs(5 * 60),
+            Self::Marathon => stats.longest_survival >= Duration::from_secs(10 * 60),
+            Self::Collector => stats.bullets_collected >= 500,
+            Self::TriggerHappy => stats.shots_fired >= 1000,
+            Self::NeverGiveUp => stats.deaths >= 25,
+        }
+    }
+}
+
+/// Achievements unlocked in any run so far.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct UnlockedAchievements(HashSet<Achievement>);
+
+impl UnlockedAchievements {
+    pub fn contains(&self, achievement: Achievement) -> bool {
+        self.0.contains(&achievement)
+    }
+
+    /// Amount of unlocked achievements.
+    pub fn count(&self) -> usize {
+        self.0.len()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn load() -> Self {
+        let Ok(content) =
+            std::fs::read_to_string(std::path::Path::new(SAVE_DIR).join(ACHIEVEMENTS_FILE))
+        else {
+            return Self::default();
+        };
+        Self(
+            content
+                .lines()
+                .filter_map(|line| {
+                    Achievement::ALL
+                        .into_iter()
+                        .find(|achievement| format!("{achievement:?}") == line)
+                })
+                .collect(),
+        )
+    }
+
+    #[cfg(target_family = "wasm")]
+    fn load() -> Self {
+        Self::default()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn save(&self) -> std::io::Result<()> {
+        let content = Achievement::ALL
+            .iter()
+            .filter(|achievement| self.contains(**achievement))
+            .map(|achievement| format!("{achievement:?}\n"))
+            .collect::<String>();
+        std::fs::create_dir_all(SAVE_DIR)?;
+        std::fs::write(
+            std::path::Path::new(SAVE_DIR).join(ACHIEVEMENTS_FILE),
+            content,
+        )
+    }
+
+    /// No file system on the web, achievements only last for the session.
+    #[cfg(target_family = "wasm")]
+    fn save(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Triggered once when an [`Achievement`] is unlocked.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct AchievementUnlocked(pub Achievement);
+
+fn check_achievements(
+    mut commands: Commands,
+    stats: Res<Statistics>,
+    mut unlocked: ResMut<UnlockedAchievements>,
+) {
+    let newly_unlocked = Achievement::ALL
+        .into_iter()
+        .filter(|achievement| !unlocked.contains(*achievement) && achievement.is_met(&stats))
+        .collect::<Vec<_>>();
+    if newly_unlocked.is_empty() {
+        return;
+    }
+
+    for achievement in newly_unlocked {
+        unlocked.0.insert(achievement);
+        commands.trigger(AchievementUnlocked(achievement));
+    }
+    if let Err(err) = unlocked.save() {
+        warn!("Failed to save achievements: {err}");
+    }
+}
+
+fn show_unlocked_toast(event: On<AchievementUnlocked>, mut commands: Commands) {
+    commands.spawn(widget::toast(
+        format!("Achievement: {}", event.0.name()),
+        event.0.description(),
+    ));
+}
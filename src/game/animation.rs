@@ -1,7 +1,7 @@
 use crate::{
     AppSystems, GameplaySet,
     direction::Direction,
-    game::{aim::AimDirection, movement::WalkDirection, player::Player},
+    game::{aim::AimDirection, dash::Dashing, movement::WalkDirection, player::Player},
 };
 use bevy::prelude::*;
 use bevy_aseprite_ultra::prelude::AseAnimation;
@@ -24,6 +24,7 @@ fn update_animation(
             &mut AseAnimation,
             &mut Sprite,
             &mut Transform,
+            Has<Dashing>,
         ),
         With<Player>,
     >,
@@ -32,10 +33,13 @@ fn update_animation(
 
     const TILT_DEG: f32 = 0.2;
 
-    for (walk_direction, aim_direction, mut ase, mut sprite, mut transform) in &mut query {
+    for (walk_direction, aim_direction, mut ase, mut sprite, mut transform, is_dashing) in
+        &mut query
+    {
         transform.rotation = Quat::IDENTITY;
 
         let (anim, anim_dir) = match (&walk_direction.0, &aim_direction.0) {
+            _ if is_dashing => ("dash".into(), walk_direction.0),
             (Some(_), Some(aim)) => (format!("walk-aim-{}", aim.abs_x()), Some(*aim)),
             (Some(walk), None) => ("walk".into(), Some(*walk)),
             (None, Some(aim)) => (format!("aim-{}", aim.abs_x()), Some(*aim)),
@@ -0,0 +1,515 @@
+//! Boss fights at survival time milestones, pausing the regular waves while they last.
+
+use crate::{
+    AppSystems, GameplaySet,
+    asset_tracking::LoadResource,
+    audio::sound_effect,
+    game::{
+        dash::Dashing,
+        enemy::{
+            EnemiesEnabled, Enemy, EnemyBudget, EnemyCount, EnemySettings, EnemySpawnQueue,
+            EnemyStunned, EnemyVariant, QueuedEnemy,
+        },
+        health::{DamageCause, DamageDealt, Dead, EntityDied, Health, deal_damage},
+        player::{Invincible, Player},
+        run_rng::RunRng,
+        survival_timer::SurvivalTimer,
+    },
+    game_state::GameOver,
+    screens::Screen,
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use rand::{Rng, seq::IndexedRandom};
+use std::{f32::consts::TAU, time::Duration};
+
+pub(super) fn plugin(app: &mut App) {
+    app.load_resource::<BossAssets>();
+    app.init_resource::<BossWaveSettings>()
+        .init_resource::<BossWaves>();
+    app.init_state::<BossFight>();
+
+    app.add_observer(end_boss_fight);
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_boss_waves);
+    app.add_systems(OnExit(Screen::Gameplay), stop_boss_fight);
+
+    app.add_systems(
+        Update,
+        (
+            spawn_boss_waves.run_if(
+                in_state(GameOver(false))
+                    .and(in_state(EnemiesEnabled(true)))
+                    .and(in_state(BossFight(false))),
+            ),
+            init_boss,
+            run_boss_attacks.run_if(in_state(EnemiesEnabled(true))),
+            update_shockwaves,
+            render_boss_health_bar,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+const BOSS_SPAWN_DISTANCE: f32 = 220.0;
+
+const WINDUP_DURATION: Duration = Duration::from_millis(600);
+const CHARGE_DURATION: Duration = Duration::from_millis(500);
+const CHARGE_SPEED: Scalar = 520.0;
+
+const SUMMON_DISTANCE: f32 = 40.0;
+
+const SHOCKWAVE_SPEED: f32 = 140.0;
+const SHOCKWAVE_MAX_RADIUS: f32 = 160.0;
+/// How close to the shockwave's edge the player has to be to get hit.
+const SHOCKWAVE_THICKNESS: f32 = 8.0;
+const SHOCKWAVE_DAMAGE: u32 = 2;
+const SHOCKWAVE_KNOCKBACK: Scalar = 700.0;
+
+/// Whether a boss fight is going on. Regular waves pause during boss fights.
+#[derive(States, Reflect, Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[reflect(State)]
+pub struct BossFight(pub bool);
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct BossWaveSettings {
+    /// Spawn a boss once the survival timer reaches each of these seconds
+    pub milestones:            Vec<u32>,
+    /// Keep spawning a boss every N seconds after the last milestone
+    pub repeat_every_n_secs:   u32,
+    /// Each following boss gets (boss_index * N) more health
+    pub extra_health_per_boss: u32,
+}
+
+impl Default for BossWaveSettings {
+    fn default() -> Self {
+        Self {
+            milestones:            vec![120, 300, 540],
+            repeat_every_n_secs:   240,
+            extra_health_per_boss: 20,
+        }
+    }
+}
+
+impl BossWaveSettings {
+    /// Survival time in seconds at which the boss with the given index spawns.
+    fn milestone(&self, boss_index: usize) -> u32 {
+        match self.milestones.get(boss_index) {
+            Some(secs) => *secs,
+            None => {
+                let last = self.milestones.last().copied().unwrap_or_default();
+                let repeats = (boss_index + 1 - self.milestones.len()) as u32;
+                last + repeats * self.repeat_every_n_secs.max(1)
+            },
+        }
+    }
+}
+
+/// Amount of bosses spawned this run.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct BossWaves(usize);
+
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+#[require(BossAttacks)]
+pub struct Boss {
+    index: usize,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+enum BossAttack {
+    Charge,
+    SummonMinions,
+    Shockwave,
+}
+
+#[derive(Reflect, Clone, Debug, Default)]
+enum BossAttackState {
+    #[default]
+    Idle,
+    WindingUp {
+        timer:  Timer,
+        attack: BossAttack,
+    },
+    Charging {
+        timer:     Timer,
+        direction: Vec2,
+    },
+}
+
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+struct BossAttacks {
+    cooldown: Timer,
+    state:    BossAttackState,
+}
+
+impl Default for BossAttacks {
+    fn default() -> Self {
+        Self {
+            cooldown: Timer::from_seconds(3.0, TimerMode::Once),
+            state:    BossAttackState::Idle,
+        }
+    }
+}
+
+/// Phase of a boss from `1` to `3`, advancing as it loses health.
+fn boss_phase(health: &Health) -> u32 {
+    let fraction = health.current() as f32 / health.max().max(1) as f32;
+    if fraction > 0.66 {
+        1
+    } else if fraction > 0.33 {
+        2
+    } else {
+        3
+    }
+}
+
+impl BossAttack {
+    fn available(phase: u32) -> &'static [Self] {
+        match phase {
+            1 => &[Self::Charge],
+            2 => &[Self::Charge, Self::SummonMinions],
+            _ => &[Self::Charge, Self::SummonMinions, Self::Shockwave],
+        }
+    }
+
+    fn cooldown(phase: u32) -> Duration {
+        Duration::from_secs_f32(3.6 - phase as f32 * 0.6)
+    }
+}
+
+/// Expanding ring damaging the player once when passing them.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+#[require(Name::new("Shockwave"), Transform, DespawnOnExit::<_>(Screen::Gameplay))]
+struct Shockwave {
+    radius: f32,
+    hit:    bool,
+}
+
+#[derive(Component)]
+struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthBarFill;
+
+#[derive(Resource, Asset, Reflect, Clone)]
+#[reflect(Resource)]
+struct BossAssets {
+    #[dependency]
+    sfx_windup:    Handle<AudioSource>,
+    #[dependency]
+    sfx_summon:    Handle<AudioSource>,
+    #[dependency]
+    sfx_shockwave: Handle<AudioSource>,
+}
+
+impl FromWorld for BossAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            sfx_windup:    assets.load("audio/sfx/shot_windup.ogg"),
+            sfx_summon:    assets.load("audio/sfx/random.ogg"),
+            sfx_shockwave: assets.load("audio/sfx/shot.ogg"),
+        }
+    }
+}
+
+fn reset_boss_waves(mut boss_waves: ResMut<BossWaves>) {
+    boss_waves.0 = 0;
+}
+
+fn stop_boss_fight(mut next_state: ResMut<NextState<BossFight>>) {
+    next_state.set(BossFight(false));
+}
+
+fn spawn_boss_waves(
+    mut commands: Commands,
+    settings: Res<BossWaveSettings>,
+    survival_time: Res<SurvivalTimer>,
+    mut boss_waves: ResMut<BossWaves>,
+    mut rng: ResMut<RunRng>,
+    mut next_state: ResMut<NextState<BossFight>>,
+    players: Query<&Transform, With<Player>>,
+) {
+    let time_s = survival_time.0.elapsed().as_secs() as u32;
+    if time_s < settings.milestone(boss_waves.0) {
+        return;
+    }
+
+    let Some(player) = players.iter().next() else {
+        return;
+    };
+
+    let angle = rng.spawns.random_range(0.0 .. TAU);
+    let position = player.translation.truncate() + Vec2::from_angle(angle) * BOSS_SPAWN_DISTANCE;
+
+    commands.spawn((
+        Enemy,
+        EnemyVariant::Boss,
+        Boss {
+            index: boss_waves.0,
+        },
+        Name::new(format!("Boss {}", boss_waves.0)),
+        Transform::from_translation(position.extend(0.0)),
+        DespawnOnExit(Screen::Gameplay),
+    ));
+    commands.spawn(boss_health_bar());
+
+    boss_waves.0 += 1;
+    next_state.set(BossFight(true));
+}
+
+/// Apply per-boss health once the variant's own health was inserted.
+fn init_boss(
+    settings: Res<BossWaveSettings>,
+    bosses: Query<(&Boss, &mut Health), Added<EnemySettings>>,
+) {
+    for (boss, mut health) in bosses {
+        *health = Health::new(health.max() + boss.index as u32 * settings.extra_health_per_boss);
+    }
+}
+
+fn run_boss_attacks(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<BossAssets>,
+    budget: Res<EnemyBudget>,
+    mut rng: ResMut<RunRng>,
+    mut count: ResMut<EnemyCount>,
+    mut queue: ResMut<EnemySpawnQueue>,
+    bosses: Query<
+        (
+            &GlobalTransform,
+            &Health,
+            &mut BossAttacks,
+            &mut LinearVelocity,
+        ),
+        (With<Boss>, Without<Dead>, Without<EnemyStunned>),
+    >,
+    players: Query<&GlobalTransform, With<Player>>,
+) {
+    for (transform, health, mut attacks, mut velocity) in bosses {
+        let position = transform.translation().truncate();
+        let phase = boss_phase(health);
+        let BossAttacks { cooldown, state } = &mut *attacks;
+
+        match state {
+            BossAttackState::Idle => {
+                cooldown.tick(time.delta());
+                if !cooldown.is_finished() {
+                    continue;
+                }
+
+                let Some(attack) = BossAttack::available(phase).choose(&mut rng.ai) else {
+                    continue;
+                };
+                commands.spawn(sound_effect(assets.sfx_windup.clone()));
+                *state = BossAttackState::WindingUp {
+                    timer:  Timer::new(WINDUP_DURATION, TimerMode::Once),
+                    attack: *attack,
+                };
+            },
+            BossAttackState::WindingUp { timer, attack } => {
+                velocity.0 = Vec2::ZERO;
+                timer.tick(time.delta());
+                if !timer.is_finished() {
+                    continue;
+                }
+
+                let attack = *attack;
+                *cooldown = Timer::new(BossAttack::cooldown(phase), TimerMode::Once);
+                *state = BossAttackState::Idle;
+
+                match attack {
+                    BossAttack::Charge => {
+                        let target = players
+                            .iter()
+                            .map(|player| player.translation().truncate())
+                            .min_by(|a, b| {
+                                a.distance_squared(position)
+                                    .total_cmp(&b.distance_squared(position))
+                            })
+                            .unwrap_or(position);
+                        *state = BossAttackState::Charging {
+                            timer:     Timer::new(CHARGE_DURATION, TimerMode::Once),
+                            direction: (target - position).normalize_or_zero(),
+                        };
+                    },
+                    BossAttack::SummonMinions => {
+                        let minions = 2 + phase;
+                        for index in 0 .. minions {
+                            let angle = index as f32 / minions as f32 * TAU;
+                            let offset = Vec2::from_angle(angle) * SUMMON_DISTANCE;
+                            if !count.try_reserve(&budget) {
+                                queue.push(QueuedEnemy {
+                                    variant:  EnemyVariant::Basic,
+                                    position: position + offset,
+                                    elite:    None,
+                                });
+                                continue;
+                            }
+                            commands.spawn((
+                                Enemy,
+                                EnemyVariant::Basic,
+                                Name::new("Boss Minion"),
+                                Transform::from_translation((position + offset).extend(0.0)),
+                                DespawnOnExit(Screen::Gameplay),
+                            ));
+                        }
+                        commands.spawn(sound_effect(assets.sfx_summon.clone()));
+                    },
+                    BossAttack::Shockwave => {
+                        commands.spawn((
+                            Shockwave::default(),
+                            Transform::from_translation(position.extend(0.0)),
+                        ));
+                        commands.spawn(sound_effect(assets.sfx_shockwave.clone()));
+                    },
+                }
+            },
+            BossAttackState::Charging { timer, direction } => {
+                velocity.0 = *direction * CHARGE_SPEED;
+                timer.tick(time.delta());
+                if timer.is_finished() {
+                    *state = BossAttackState::Idle;
+                }
+            },
+        }
+    }
+}
+
+fn update_shockwaves(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    shockwaves: Query<(Entity, &GlobalTransform, &mut Shockwave)>,
+    mut players: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &mut LinearVelocity,
+            Option<&mut Health>,
+            Has<Invincible>,
+            Has<Dashing>,
+        ),
+        (With<Player>, Without<Dead>),
+    >,
+) {
+    for (entity, transform, mut shockwave) in shockwaves {
+        shockwave.radius += SHOCKWAVE_SPEED * time.delta_secs();
+        if shockwave.radius > SHOCKWAVE_MAX_RADIUS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let center = transform.translation().truncate();
+        let alpha = 1.0 - shockwave.radius / SHOCKWAVE_MAX_RADIUS;
+        gizmos.circle_2d(center, shockwave.radius, Color::hsla(20.0, 1.0, 0.6, alpha));
+
+        if shockwave.hit {
+            continue;
+        }
+
+        for (player, player_transform, mut velocity, health, is_invincible, is_dashing) in
+            &mut players
+        {
+            let offset = player_transform.translation().truncate() - center;
+            if (offset.length() - shockwave.radius).abs() > SHOCKWAVE_THICKNESS {
+                continue;
+            }
+
+            shockwave.hit = true;
+            velocity.0 += offset.normalize_or_zero() * SHOCKWAVE_KNOCKBACK;
+
+            if is_invincible || is_dashing {
+                continue;
+            }
+            if let Some(mut health) = health {
+                deal_damage(
+                    &mut commands,
+                    &mut health,
+                    DamageDealt::new(player, SHOCKWAVE_DAMAGE, DamageCause::Shockwave),
+                );
+            }
+        }
+    }
+}
+
+fn end_boss_fight(
+    event: On<EntityDied>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<BossFight>>,
+    bosses: Query<(), With<Boss>>,
+    bars: Query<Entity, With<BossHealthBar>>,
+) {
+    if !bosses.contains(event.entity) {
+        return;
+    }
+
+    for bar in bars {
+        commands.entity(bar).despawn();
+    }
+    next_state.set(BossFight(false));
+}
+
+fn boss_health_bar() -> impl Bundle {
+    (
+        Name::new("Boss Health Bar"),
+        BossHealthBar,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(16.0),
+            width: percent(100),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        DespawnOnExit(Screen::Gameplay),
+        Pickable::IGNORE,
+        children![
+            (
+                Text::new("BOSS"),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+            ),
+            (
+                Node {
+                    width: Val::Px(240.0),
+                    height: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(Color::hsla(0.0, 0.0, 0.1, 0.8)),
+                children![(
+                    BossHealthBarFill,
+                    Node {
+                        width: percent(100),
+                        height: percent(100),
+                        ..default()
+                    },
+                    BackgroundColor(Color::hsl(0.0, 0.9, 0.5)),
+                )],
+            ),
+        ],
+    )
+}
+
+fn render_boss_health_bar(
+    bosses: Query<&Health, (With<Boss>, Changed<Health>)>,
+    fills: Query<&mut Node, With<BossHealthBarFill>>,
+) {
+    let Some(health) = bosses.iter().next() else {
+        return;
+    };
+
+    let fraction = health.current() as f32 / health.max().max(1) as f32;
+    for mut node in fills {
+        node.width = percent(fraction * 100.0);
+    }
+}
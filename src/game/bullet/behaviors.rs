@@ -0,0 +1,278 @@
+//! Collision behaviors of the alternate [`BulletType`]s.
+
+use super::{
+    BulletAssets, BulletBounces, BulletHit, BulletProfile, BulletSpawner, BulletStats, BulletTimer,
+    BulletType, Collectable, spawn_bullet,
+};
+use crate::{
+    AppSystems, GameplaySet,
+    audio::sound_effect,
+    game::{
+        enemy::{Enemy, EnemySettings, EnemyStunned},
+        health::{DamageCause, DamageDealt, Dead, Health, deal_damage},
+        particles::{ParticleColor, ParticleEmitter, particle_burst},
+        util::CollisionTag,
+    },
+};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(split_on_hit);
+
+    app.add_systems(
+        Update,
+        (
+            handle_piercing_bullets,
+            explode_stopped_bullets,
+            start_boomerang_return,
+            steer_returning_boomerangs,
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Rough radius of an unscaled enemy, for hit detection without collisions.
+pub(super) const ENEMY_RADIUS: f32 = 8.0;
+
+const SPLIT_ANGLE: f32 = 30.0;
+const SHARD_SCALE: f32 = 0.5;
+const SHARD_DURATION: Duration = Duration::from_millis(400);
+
+const EXPLOSION_RADIUS: f32 = 40.0;
+const EXPLOSION_DAMAGE: u32 = 2;
+const EXPLOSION_KNOCKBACK: f32 = 1.5;
+
+const BOOMERANG_STEERING: f32 = 8.0;
+const BOOMERANG_RETURN_DURATION: Duration = Duration::from_secs(2);
+
+#[derive(Component, Default)]
+struct Piercing {
+    hit: Vec<Entity>,
+}
+
+#[derive(Component)]
+struct Splitting;
+
+/// Short lived fragment of a [`BulletType::Splitting`] bullet, despawns instead of being
+/// collectable.
+#[derive(Component)]
+pub(super) struct BulletShard;
+
+#[derive(Component)]
+struct Explosive;
+
+#[derive(Component)]
+struct Boomerang;
+
+#[derive(Component)]
+struct Returning(Timer);
+
+pub(super) fn insert_behavior(bullet: &mut EntityCommands, bullet_type: BulletType) {
+    match bullet_type {
+        BulletType::Standard | BulletType::Heavy => {},
+        BulletType::Piercing => {
+            bullet.insert((
+                Piercing::default(),
+                CollisionLayers::new(
+                    [CollisionTag::Bullet, CollisionTag::Entity],
+                    CollisionTag::Solid,
+                ),
+            ));
+        },
+        BulletType::Splitting => {
+            bullet.insert(Splitting);
+        },
+        BulletType::Explosive => {
+            bullet.insert(Explosive);
+        },
+        BulletType::Boomerang => {
+            bullet.insert(Boomerang);
+        },
+    }
+}
+
+fn handle_piercing_bullets(
+    mut commands: Commands,
+    bullets: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &LinearVelocity,
+            &BulletStats,
+            &BulletProfile,
+            &BulletBounces,
+            &mut Piercing,
+        ),
+        Without<Collectable>,
+    >,
+    enemies: Query<(Entity, &GlobalTransform), (With<Enemy>, Without<Dead>)>,
+) {
+    for (bullet, transform, velocity, stats, profile, bounces, mut piercing) in bullets {
+        let position = transform.translation().truncate();
+        for (enemy, enemy_transform) in enemies {
+            if piercing.hit.contains(&enemy) {
+                continue;
+            }
+
+            let reach = stats.radius + ENEMY_RADIUS * enemy_transform.scale().x;
+            if position.distance(enemy_transform.translation().truncate()) > reach {
+                continue;
+            }
+
+            piercing.hit.push(enemy);
+            commands.trigger(BulletHit {
+                enemy,
+                bullet,
+                direction: velocity.normalize_or_zero(),
+                damage: profile.damage_after_bounces(bounces.0),
+                knockback: stats.enemy_knockback,
+                cause: DamageCause::Bullet,
+            });
+        }
+    }
+}
+
+fn split_on_hit(
+    hit: On<BulletHit>,
+    mut commands: Commands,
+    assets: Res<BulletAssets>,
+    bullets: Query<(&GlobalTransform, &LinearVelocity), With<Splitting>>,
+) {
+    let Ok((transform, velocity)) = bullets.get(hit.bullet) else {
+        return;
+    };
+
+    commands.entity(hit.bullet).remove::<Splitting>();
+
+    let translation = transform.translation();
+    let direction = velocity.normalize_or(hit.direction);
+    for angle in [-SPLIT_ANGLE, SPLIT_ANGLE] {
+        let shard_direction = Vec2::from_angle(angle.to_radians()).rotate(direction);
+        spawn_bullet(
+            &mut commands,
+            &assets,
+            BulletType::Standard,
+            translation,
+            shard_direction,
+        )
+        .insert((
+            BulletShard,
+            BulletTimer(Timer::new(SHARD_DURATION, TimerMode::Once)),
+            Transform::from_translation(translation).with_scale(Vec3::splat(SHARD_SCALE)),
+        ));
+    }
+}
+
+fn explode_stopped_bullets(
+    mut commands: Commands,
+    assets: Res<BulletAssets>,
+    bullets: Query<(Entity, &GlobalTransform), (Added<Collectable>, With<Explosive>)>,
+    mut enemies: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &EnemySettings,
+            &mut LinearVelocity,
+            &mut Health,
+            Has<EnemyStunned>,
+        ),
+        (With<Enemy>, Without<Dead>),
+    >,
+) {
+    for (bullet, transform) in bullets {
+        let center = transform.translation().truncate();
+
+        // Damaged directly instead of through `BulletHit`, which spares already stunned enemies
+        for (enemy, enemy_transform, settings, mut velocity, mut health, is_stunned) in &mut enemies
+        {
+            let offset = enemy_transform.translation().truncate() - center;
+            if offset.length() > EXPLOSION_RADIUS {
+                continue;
+            }
+
+            velocity.0 += offset.normalize_or_zero()
+                * settings.knockback_strength_bullet
+                * EXPLOSION_KNOCKBACK;
+            if !is_stunned {
+                commands.entity(enemy).insert(EnemyStunned);
+            }
+            deal_damage(
+                &mut commands,
+                &mut health,
+                DamageDealt::new(enemy, EXPLOSION_DAMAGE, DamageCause::Explosion)
+                    .with_source(bullet),
+            );
+        }
+
+        commands.spawn(sound_effect(assets.sfx_explosion.clone()));
+        commands.spawn(particle_burst(
+            ParticleEmitter::burst(48)
+                .with_speed(60.0, 160.0)
+                .with_lifetime(0.2, 0.6)
+                .with_size(3.0)
+                .with_color(ParticleColor {
+                    start:     Color::hsl(50.0, 1.0, 0.7),
+                    end:       Color::hsla(0.0, 1.0, 0.4, 0.0),
+                    hue_shift: 0.0,
+                }),
+            transform.translation(),
+        ));
+        commands.entity(bullet).remove::<Explosive>();
+    }
+}
+
+fn start_boomerang_return(
+    mut commands: Commands,
+    bullets: Query<Entity, (Added<Collectable>, With<Boomerang>)>,
+) {
+    for bullet in bullets {
+        commands.entity(bullet).remove::<Boomerang>().insert((
+            Returning(Timer::new(BOOMERANG_RETURN_DURATION, TimerMode::Once)),
+            LinearDamping(0.0),
+        ));
+    }
+}
+
+fn steer_returning_boomerangs(
+    mut commands: Commands,
+    time: Res<Time>,
+    bullets: Query<(
+        Entity,
+        &GlobalTransform,
+        &BulletStats,
+        &mut LinearVelocity,
+        &mut Returning,
+    )>,
+    spawners: Query<&GlobalTransform, With<BulletSpawner>>,
+) {
+    let dt = time.delta_secs();
+
+    for (bullet, transform, stats, mut velocity, mut returning) in bullets {
+        returning.0.tick(time.delta());
+        if returning.0.is_finished() {
+            commands
+                .entity(bullet)
+                .remove::<Returning>()
+                .insert(LinearDamping(stats.velocity_damping));
+            continue;
+        }
+
+        let position = transform.translation().truncate();
+        let Some(target) = spawners
+            .iter()
+            .map(|spawner| spawner.translation().truncate())
+            .min_by(|a, b| {
+                a.distance_squared(position)
+                    .total_cmp(&b.distance_squared(position))
+            })
+        else {
+            continue;
+        };
+
+        let desired = (target - position).normalize_or_zero() * stats.speed;
+        velocity.0 = velocity.0.lerp(desired, (BOOMERANG_STEERING * dt).min(1.0));
+    }
+}
@@ -0,0 +1,193 @@
+//! Hold-to-charge shots: holding Shoot builds charge, releasing fires a stronger bullet.
+
+use super::{BulletAssets, BulletAvailable, BulletSpawner, EquippedBulletType, fire_bullet};
+use crate::{
+    AppSystems, GameplaySet,
+    audio::sound_effect,
+    game::{
+        aim::AimDirection,
+        health::Dead,
+        movement::Stunned,
+        particles::{ParticleColor, ParticleEmitter, particle_burst},
+    },
+    input::{ActionState, PlayerAction},
+};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ChargeShotEnabled>();
+
+    app.add_systems(
+        Update,
+        (spawn_charge_bar, update_shot_charge, render_charge_bar)
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Seconds Shoot has to be held for a fully charged shot.
+const FULL_CHARGE: f32 = 1.0;
+/// Seconds after which holding Shoot overcharges and stuns the player.
+const OVERCHARGE: f32 = 2.5;
+const OVERCHARGE_STUN: Duration = Duration::from_secs(1);
+
+pub(super) const MAX_SPEED_MULTIPLIER: f32 = 1.75;
+pub(super) const MAX_DAMAGE_MULTIPLIER: f32 = 2.0;
+pub(super) const MAX_RECOIL_MULTIPLIER: f32 = 2.0;
+
+const BAR_SIZE: Vec2 = Vec2::new(16.0, 2.0);
+const BAR_OFFSET: Vec3 = Vec3::new(0.0, 13.0, 1.0);
+
+/// Hold Shoot to charge shots, instead of firing on press.
+#[derive(Resource, Reflect, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct ChargeShotEnabled(pub bool);
+
+impl Default for ChargeShotEnabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Seconds Shoot has been held for by a [`BulletSpawner`].
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[component(storage = "SparseSet")]
+#[reflect(Component)]
+pub(super) struct ShotCharge(pub f32);
+
+impl ShotCharge {
+    /// Charge from `0.0` to `1.0`.
+    pub(super) fn amount(&self) -> f32 {
+        (self.0 / FULL_CHARGE).min(1.0)
+    }
+}
+
+/// Scale a value by charge, from `1.0` when uncharged up to `max` when fully charged.
+pub(super) fn charge_multiplier(charge: f32, max: f32) -> f32 {
+    1.0 + (max - 1.0) * charge
+}
+
+#[derive(Component)]
+struct ChargeBar;
+
+#[derive(Component)]
+struct ChargeBarFill;
+
+fn spawn_charge_bar(mut commands: Commands, spawners: Query<Entity, Added<BulletSpawner>>) {
+    for spawner in spawners {
+        commands.entity(spawner).with_children(|parent| {
+            parent.spawn((
+                Name::new("ChargeBar"),
+                ChargeBar,
+                Sprite::from_color(Color::hsla(0.0, 0.0, 0.1, 0.8), BAR_SIZE),
+                Transform::from_translation(BAR_OFFSET),
+                Visibility::Hidden,
+            ));
+            parent.spawn((
+                Name::new("ChargeBarFill"),
+                ChargeBarFill,
+                Sprite::from_color(Color::hsl(50.0, 1.0, 0.6), BAR_SIZE),
+                Transform::from_translation(BAR_OFFSET + Vec3::Z * 0.1),
+                Visibility::Hidden,
+            ));
+        });
+    }
+}
+
+fn update_shot_charge(
+    mut commands: Commands,
+    time: Res<Time>,
+    action_state: Res<ActionState<PlayerAction>>,
+    assets: Res<BulletAssets>,
+    spawners: Query<
+        (
+            Entity,
+            &mut ShotCharge,
+            &Transform,
+            &AimDirection,
+            &EquippedBulletType,
+            &mut LinearVelocity,
+            Has<BulletAvailable>,
+        ),
+        (With<BulletSpawner>, Without<Dead>),
+    >,
+) {
+    for (entity, mut charge, transform, aim, equipped, mut velocity, has_bullet) in spawners {
+        if !has_bullet {
+            commands.entity(entity).remove::<ShotCharge>();
+            continue;
+        }
+
+        if action_state.pressed(&PlayerAction::Shoot) {
+            charge.0 += time.delta_secs();
+            if charge.0 >= OVERCHARGE {
+                commands
+                    .entity(entity)
+                    .remove::<ShotCharge>()
+                    .insert(Stunned(Timer::new(OVERCHARGE_STUN, TimerMode::Once)));
+                commands.spawn(sound_effect(assets.sfx_blank.clone()));
+                commands.spawn(particle_burst(
+                    ParticleEmitter::burst(24)
+                        .with_speed(40.0, 120.0)
+                        .with_lifetime(0.2, 0.6)
+                        .with_color(ParticleColor {
+                            hue_shift: 40.0,
+                            ..ParticleColor::fade(Color::hsl(0.0, 1.0, 0.6))
+                        }),
+                    transform.translation,
+                ));
+            }
+            continue;
+        }
+
+        commands.entity(entity).remove::<ShotCharge>();
+        velocity.0 += fire_bullet(
+            &mut commands,
+            &assets,
+            entity,
+            transform.translation,
+            aim,
+            equipped.0,
+            charge.amount(),
+        );
+    }
+}
+
+fn render_charge_bar(
+    spawners: Query<Option<&ShotCharge>, With<BulletSpawner>>,
+    bars: Query<(&ChildOf, &mut Visibility), (With<ChargeBar>, Without<ChargeBarFill>)>,
+    fills: Query<(&ChildOf, &mut Transform, &mut Sprite, &mut Visibility), With<ChargeBarFill>>,
+) {
+    for (parent, mut visibility) in bars {
+        let charging = matches!(spawners.get(parent.parent()), Ok(Some(_)));
+        *visibility = if charging {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    for (parent, mut transform, mut sprite, mut visibility) in fills {
+        let Ok(Some(charge)) = spawners.get(parent.parent()) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let amount = charge.amount();
+        *visibility = Visibility::Inherited;
+        transform.scale.x = amount;
+        transform.translation.x = BAR_OFFSET.x - BAR_SIZE.x * (1.0 - amount) * 0.5;
+
+        // Flash red once fully charged, warning of the overcharge
+        sprite.color = if amount >= 1.0 {
+            let warning = ((charge.0 - FULL_CHARGE) / (OVERCHARGE - FULL_CHARGE)).clamp(0.0, 1.0);
+            let flash = (charge.0 * (8.0 + warning * 16.0)).sin() * 0.5 + 0.5;
+            Color::hsl(0.0, 1.0, 0.5 + flash * 0.2)
+        } else {
+            Color::hsl(50.0 - amount * 30.0, 1.0, 0.6)
+        };
+    }
+}
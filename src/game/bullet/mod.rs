@@ -0,0 +1,749 @@
+use crate::{
+    AppSystems, GameplaySet,
+    asset_tracking::LoadResource,
+    audio::sound_effect,
+    game::{
+        aim::AimDirection,
+        enemy::{Elite, EliteAffix, Enemy, EnemySettings, EnemyStunned, Facing},
+        health::{DamageCause, DamageDealt, Dead, Health, deal_damage},
+        movement::{ModifierSource, MovementModifiers, Stunned},
+        particles::{ParticleColor, ParticleEmitter, particle_burst},
+        util::CollisionTag,
+        visuals::{AnimationDirection, HueAnimation, SetSpriteColor, VisualAnimation},
+    },
+    input::{PlayerAction, action_just_pressed},
+    screens::Screen,
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use bevy_aseprite_ultra::prelude::{Animation, AseAnimation, Aseprite};
+use rand::Rng;
+
+mod behaviors;
+mod charge;
+mod pickup;
+mod recall;
+mod types;
+
+pub use charge::ChargeShotEnabled;
+pub use recall::{RecallCooldown, RecallSettings, RecallValueUi};
+pub use types::{BulletStats, BulletType};
+
+pub fn plugin(app: &mut App) {
+    app.load_resource::<BulletAssets>();
+    app.init_resource::<TrajectoryPreviewEnabled>()
+        .init_resource::<RunBulletType>();
+
+    app.add_plugins((
+        behaviors::plugin,
+        charge::plugin,
+        pickup::plugin,
+        recall::plugin,
+    ));
+
+    app.add_observer(apply_bullet_hit);
+
+    app.add_systems(
+        Update,
+        handle_bullet_timers
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            init_equipped_bullet_type,
+            handle_spawn_bullet.run_if(action_just_pressed(PlayerAction::Shoot)),
+            apply_bullet_bounces,
+            draw_trajectory_preview.run_if(resource_equals(TrajectoryPreviewEnabled(true))),
+            render_equipped_bullet_type,
+            apply_bullet_movement_modifier,
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+#[require(
+    Name::new("Bullet"),
+    DespawnOnExit::<_>(Screen::Gameplay),
+    Sprite::default(),
+    RigidBody::Dynamic,
+    Collider::circle(6.5),
+    Mass(0.5),
+    CollisionLayers::new(
+        [CollisionTag::Bullet, CollisionTag::Entity],
+        [CollisionTag::Solid, CollisionTag::Enemy],
+    ),
+    Restitution {
+        coefficient: 0.8,
+        combine_rule: CoefficientCombine::Max
+    },
+    MaxLinearSpeed(200.0),
+    AngularDamping(1.0),
+    CollisionEventsEnabled,
+    BulletBounces,
+
+    SetSpriteColor(Color::hsl(0.0, 0.9, 0.4)),
+    HueAnimation(VisualAnimation {
+        range: Some((0.0, 70.0)),
+        period: 1.0,
+        direction: AnimationDirection::Boomerang,
+        ..default()
+    }),
+)]
+pub struct Bullet;
+
+/// Physics tuning of a fired bullet.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct BulletProfile {
+    /// Max random deflection in degrees of the ricochet direction when bouncing off solids
+    pub solid_deflection:      f32,
+    /// Max random deflection in degrees of the ricochet direction when hitting enemies
+    pub enemy_deflection:      f32,
+    /// Speed multiplier applied on every enemy hit
+    pub enemy_speed_gain:      Scalar,
+    pub max_speed:             Scalar,
+    pub damage:                u32,
+    /// Additional damage multiplier per bounce, `0.5` deals 150% damage after one bounce
+    pub bounce_damage_scaling: f32,
+    pub max_damage_multiplier: f32,
+}
+
+impl Default for BulletProfile {
+    fn default() -> Self {
+        Self {
+            solid_deflection:      8.0,
+            enemy_deflection:      20.0,
+            enemy_speed_gain:      1.15,
+            max_speed:             300.0,
+            damage:                1,
+            bounce_damage_scaling: 0.5,
+            max_damage_multiplier: 3.0,
+        }
+    }
+}
+
+impl BulletProfile {
+    /// Damage dealt after having bounced the given amount of times.
+    pub fn damage_after_bounces(&self, bounces: u32) -> u32 {
+        let multiplier =
+            (1.0 + self.bounce_damage_scaling * bounces as f32).min(self.max_damage_multiplier);
+        (self.damage as f32 * multiplier).round() as u32
+    }
+}
+
+/// Amount of times a bullet has bounced off solids or enemies since being fired.
+#[derive(Component, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Component)]
+pub struct BulletBounces(pub u32);
+
+/// Bounce to apply to the bullet's velocity once the physics step has resolved the contact.
+#[derive(Component, Clone, Copy)]
+#[component(storage = "SparseSet")]
+struct PendingBounce {
+    deflection: f32,
+    speed_gain: Scalar,
+}
+
+/// A bullet hit an enemy hard enough to hurt it.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct BulletHit {
+    #[event_target]
+    pub enemy:     Entity,
+    pub bullet:    Entity,
+    /// Direction the enemy is knocked back in
+    pub direction: Vec2,
+    pub damage:    u32,
+    /// Multiplier of the enemy's own knockback
+    pub knockback: Scalar,
+    pub cause:     DamageCause,
+}
+
+/// Triggered when a [`BulletSpawner`] fires its bullet.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct BulletFired {
+    #[event_target]
+    pub spawner: Entity,
+}
+
+/// Triggered when a [`BulletSpawner`] picks its bullet back up.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct BulletCollected {
+    #[event_target]
+    pub spawner: Entity,
+}
+
+/// Draw the predicted bullet path while aiming.
+#[derive(Resource, Reflect, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource)]
+pub struct TrajectoryPreviewEnabled(pub bool);
+
+/// Bullet type the player starts a run with.
+#[derive(Resource, Reflect, Clone, Copy, Default)]
+#[reflect(Resource)]
+pub struct RunBulletType(pub BulletType);
+
+/// Movement speed multiplier of a [`BulletSpawner`] while carrying its bullet.
+const CARRYING_BULLET_SPEED: Scalar = 0.85;
+/// Movement speed multiplier of a [`BulletSpawner`] while its bullet is out.
+const WITHOUT_BULLET_SPEED: Scalar = 1.1;
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+#[require(BulletAvailable, EquippedBulletType, RecallCooldown)]
+pub struct BulletSpawner;
+
+/// Bullet type fired by a [`BulletSpawner`].
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct EquippedBulletType(pub BulletType);
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct BulletTypeValueUi;
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[component(storage = "SparseSet")]
+#[reflect(Component)]
+struct BulletAvailable;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct BulletTimer(Timer);
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Collectable;
+
+#[derive(Resource, Asset, Reflect, Clone)]
+#[reflect(Resource)]
+struct BulletAssets {
+    #[dependency]
+    spritesheet:   Handle<Aseprite>,
+    #[dependency]
+    sfx_shoot:     Handle<AudioSource>,
+    #[dependency]
+    sfx_blank:     Handle<AudioSource>,
+    #[dependency]
+    sfx_collect:   Handle<AudioSource>,
+    #[dependency]
+    sfx_explosion: Handle<AudioSource>,
+    spawn_offset:  Scalar,
+}
+
+impl FromWorld for BulletAssets {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            spritesheet:   world
+                .resource::<AssetServer>()
+                .load("spritesheets/bullet.ase"),
+            sfx_shoot:     world.resource::<AssetServer>().load("audio/sfx/shoot.ogg"),
+            sfx_blank:     world.resource::<AssetServer>().load("audio/sfx/blip.ogg"),
+            sfx_collect:   world.resource::<AssetServer>().load("audio/sfx/reload.ogg"),
+            sfx_explosion: world
+                .resource::<AssetServer>()
+                .load("audio/sfx/explosion.ogg"),
+            spawn_offset:  8.0,
+        }
+    }
+}
+
+fn spawn_bullet<'a>(
+    commands: &'a mut Commands,
+    assets: &BulletAssets,
+    bullet_type: BulletType,
+    translation: Vec3,
+    direction: Vec2,
+) -> EntityCommands<'a> {
+    let stats = bullet_type.stats();
+    let profile = bullet_type.profile();
+
+    let mut bullet = commands.spawn((
+        Bullet,
+        bullet_type,
+        DespawnOnExit(Screen::Gameplay),
+        BulletTimer(Timer::new(stats.duration, TimerMode::Once)),
+        AseAnimation {
+            aseprite:  assets.spritesheet.clone(),
+            animation: Animation::tag("fly"),
+        },
+        // The collider is scaled along with the sprite
+        Transform::from_translation(translation).with_scale(Vec3::splat(stats.scale())),
+        (
+            LinearVelocity(direction * stats.speed),
+            MaxLinearSpeed(profile.max_speed),
+            Collider::circle(BulletStats::default().radius),
+            Mass(stats.mass),
+            Restitution {
+                coefficient:  stats.restitution,
+                combine_rule: CoefficientCombine::Max,
+            },
+        ),
+        HueAnimation(VisualAnimation {
+            range: Some(stats.hue_range),
+            period: 1.0,
+            direction: AnimationDirection::Boomerang,
+            ..default()
+        }),
+        trail_particles(stats.hue_range.0),
+        stats,
+        profile,
+    ));
+    bullet
+        .observe(handle_collect_bullet)
+        .observe(handle_bullet_ricochet)
+        .observe(handle_bullet_enemy_collision);
+    behaviors::insert_behavior(&mut bullet, bullet_type);
+    bullet
+}
+
+fn init_equipped_bullet_type(
+    run_bullet_type: Res<RunBulletType>,
+    spawners: Query<&mut EquippedBulletType, Added<BulletSpawner>>,
+) {
+    for mut equipped in spawners {
+        equipped.0 = run_bullet_type.0;
+    }
+}
+
+fn handle_spawn_bullet(
+    mut commands: Commands,
+    assets: Res<BulletAssets>,
+    charge_shot: Res<ChargeShotEnabled>,
+    spawners: Query<
+        (
+            Entity,
+            Has<BulletAvailable>,
+            &Transform,
+            &AimDirection,
+            &EquippedBulletType,
+            &mut LinearVelocity,
+        ),
+        (With<BulletSpawner>, Without<Stunned>, Without<Dead>),
+    >,
+) {
+    for (entity, has_bullet, transform, aim, equipped, mut velocity) in spawners {
+        if !has_bullet {
+            commands.spawn(sound_effect(assets.sfx_blank.clone()));
+            continue;
+        }
+
+        if charge_shot.0 {
+            commands
+                .entity(entity)
+                .insert(charge::ShotCharge::default());
+        } else {
+            velocity.0 += fire_bullet(
+                &mut commands,
+                &assets,
+                entity,
+                transform.translation,
+                aim,
+                equipped.0,
+                0.0,
+            );
+        }
+    }
+}
+
+/// Fire the spawner's bullet in its aim direction, with speed, damage and recoil scaled by
+/// `charge` (`0.0` to `1.0`). Returns the recoil to apply to the spawner.
+fn fire_bullet(
+    commands: &mut Commands,
+    assets: &BulletAssets,
+    spawner: Entity,
+    translation: Vec3,
+    aim: &AimDirection,
+    bullet_type: BulletType,
+    charge: f32,
+) -> Vec2 {
+    let Some(dir) = &aim.0 else {
+        return Vec2::ZERO;
+    };
+
+    let stats = bullet_type.stats();
+    let mut profile = bullet_type.profile();
+    let speed = charge::charge_multiplier(charge, charge::MAX_SPEED_MULTIPLIER);
+    let damage = charge::charge_multiplier(charge, charge::MAX_DAMAGE_MULTIPLIER);
+    let recoil = charge::charge_multiplier(charge, charge::MAX_RECOIL_MULTIPLIER);
+
+    let dir_vec = dir.vec();
+    let offset = (dir_vec * assets.spawn_offset).extend(0.0);
+    let knockback = dir.opposite().vec() * stats.player_knockback * recoil;
+
+    profile.max_speed *= speed;
+    profile.damage = (profile.damage as f32 * damage).round() as u32;
+
+    commands.spawn(sound_effect(assets.sfx_shoot.clone()));
+    spawn_bullet(commands, assets, bullet_type, translation + offset, dir_vec).insert((
+        LinearVelocity(dir_vec * stats.speed * speed),
+        MaxLinearSpeed(profile.max_speed),
+        profile,
+    ));
+
+    commands.entity(spawner).remove::<BulletAvailable>();
+    commands.trigger(BulletFired { spawner });
+
+    knockback
+}
+
+fn handle_bullet_timers(
+    mut commands: Commands,
+    time: Res<Time>,
+    bullets: Query<
+        (
+            Entity,
+            &mut BulletTimer,
+            &BulletStats,
+            &GlobalTransform,
+            &mut AseAnimation,
+            &mut CollisionLayers,
+            Has<behaviors::BulletShard>,
+        ),
+        (
+            With<Bullet>,
+            Without<Collectable>,
+            Without<recall::Recalling>,
+        ),
+    >,
+) {
+    let delta = time.delta();
+    for (entity, mut timer, stats, transform, mut ase, mut collision_layers, is_shard) in bullets {
+        timer.0.tick(delta);
+        if !timer.0.is_finished() {
+            continue;
+        }
+
+        if is_shard {
+            commands.spawn(particle_burst(
+                ParticleEmitter::burst(4)
+                    .with_lifetime(0.1, 0.3)
+                    .with_color(ParticleColor::fade(Color::hsl(stats.hue_range.0, 0.9, 0.6))),
+                transform.translation(),
+            ));
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        ase.animation.play_loop("idle");
+        collision_layers.memberships = CollisionTag::Collectable.into();
+        collision_layers.filters |= CollisionTag::Player;
+        commands
+            .entity(entity)
+            .remove::<(BulletTimer, ParticleEmitter)>()
+            .insert((Collectable, LinearDamping(stats.velocity_damping)));
+    }
+}
+
+fn handle_collect_bullet(
+    trigger: On<CollisionStart>,
+    mut commands: Commands,
+    assets: Res<BulletAssets>,
+    bullets: Query<
+        &GlobalTransform,
+        (
+            With<Bullet>,
+            Or<(With<Collectable>, With<recall::Recalling>)>,
+        ),
+    >,
+    spawners: Query<(), (With<BulletSpawner>, Without<BulletAvailable>)>,
+) {
+    let bullet = trigger.collider1;
+    let spawner = trigger.collider2;
+    if let Ok(transform) = bullets.get(bullet)
+        && spawners.contains(spawner)
+    {
+        commands.spawn(particle_burst(
+            ParticleEmitter::burst(16)
+                .with_speed(40.0, 80.0)
+                .with_lifetime(0.2, 0.5)
+                .with_color(ParticleColor {
+                    start:     Color::hsl(120.0, 0.9, 0.7),
+                    end:       Color::hsla(60.0, 0.9, 0.7, 0.0),
+                    hue_shift: 0.0,
+                }),
+            transform.translation(),
+        ));
+        commands.entity(bullet).despawn();
+        commands.entity(spawner).insert(BulletAvailable);
+        commands.trigger(BulletCollected { spawner });
+        commands.spawn(sound_effect(assets.sfx_collect.clone()));
+    }
+}
+
+fn handle_bullet_enemy_collision(
+    trigger: On<CollisionStart>,
+    mut commands: Commands,
+    bullets: Query<
+        (
+            &GlobalTransform,
+            &LinearVelocity,
+            &BulletStats,
+            &BulletProfile,
+            &BulletBounces,
+        ),
+        (With<Bullet>, Without<Enemy>),
+    >,
+    enemies: Query<&GlobalTransform, (With<Enemy>, Without<Dead>, Without<Bullet>)>,
+) {
+    let bullet = trigger.collider1;
+    let enemy = trigger.collider2;
+
+    if let (Ok((bullet_transform, bullet_velocity, stats, profile, bounces)), Ok(enemy_transform)) =
+        (bullets.get(bullet), enemies.get(enemy))
+    {
+        const MAX_SPEED_FOR_DAMAGE: f32 = 30.0;
+
+        if bullet_velocity.length() < MAX_SPEED_FOR_DAMAGE {
+            return;
+        }
+
+        let bullet_translation = bullet_transform.translation();
+        let enemy_translation = enemy_transform.translation();
+
+        let direction =
+            (enemy_translation.truncate() - bullet_translation.truncate()).normalize_or_zero();
+
+        commands.entity(bullet).insert(PendingBounce {
+            deflection: profile.enemy_deflection,
+            speed_gain: profile.enemy_speed_gain,
+        });
+
+        commands.trigger(BulletHit {
+            enemy,
+            bullet,
+            direction,
+            damage: profile.damage_after_bounces(bounces.0),
+            knockback: stats.enemy_knockback,
+            cause: DamageCause::Bullet,
+        });
+    }
+}
+
+fn apply_bullet_hit(
+    hit: On<BulletHit>,
+    mut commands: Commands,
+    mut enemies: Query<
+        (
+            &GlobalTransform,
+            &EnemySettings,
+            &mut LinearVelocity,
+            Option<&mut Health>,
+            &Facing,
+            Option<&Elite>,
+            Has<EnemyStunned>,
+        ),
+        (With<Enemy>, Without<Dead>),
+    >,
+) {
+    let Ok((transform, settings, mut velocity, health, facing, elite, is_stunned)) =
+        enemies.get_mut(hit.enemy)
+    else {
+        return;
+    };
+
+    if elite.is_some_and(|elite| elite.blocks_hit(facing.0, hit.direction)) {
+        commands.spawn(particle_burst(
+            ParticleEmitter::burst(6)
+                .with_cone(-hit.direction, 0.8)
+                .with_speed(60.0, 120.0)
+                .with_lifetime(0.1, 0.25)
+                .with_color(ParticleColor::fade(EliteAffix::Shielded.color())),
+            transform.translation(),
+        ));
+        return;
+    }
+
+    velocity.0 += hit.direction * settings.knockback_strength_bullet * hit.knockback;
+
+    commands.spawn(particle_burst(
+        ParticleEmitter::burst(8)
+            .with_cone(hit.direction, 1.2)
+            .with_speed(40.0, 100.0)
+            .with_lifetime(0.15, 0.4)
+            .with_color(ParticleColor {
+                hue_shift: 90.0,
+                ..ParticleColor::fade(Color::hsl(0.0, 0.9, 0.6))
+            }),
+        transform.translation(),
+    ));
+
+    if !is_stunned {
+        commands.entity(hit.enemy).insert(EnemyStunned);
+
+        if let Some(mut health) = health {
+            deal_damage(
+                &mut commands,
+                &mut health,
+                DamageDealt::new(hit.enemy, hit.damage, hit.cause).with_source(hit.bullet),
+            );
+        }
+    }
+}
+
+fn handle_bullet_ricochet(
+    trigger: On<CollisionStart>,
+    mut commands: Commands,
+    bullets: Query<
+        (&GlobalTransform, &LinearVelocity, &BulletProfile),
+        (
+            With<Bullet>,
+            Without<Collectable>,
+            Without<recall::Recalling>,
+        ),
+    >,
+    enemies: Query<(), With<Enemy>>,
+) {
+    let bullet = trigger.collider1;
+    let other = trigger.collider2;
+
+    if let Ok((transform, velocity, profile)) = bullets.get(bullet)
+        && !enemies.contains(other)
+    {
+        commands.entity(bullet).insert(PendingBounce {
+            deflection: profile.solid_deflection,
+            speed_gain: 1.0,
+        });
+        commands.spawn(particle_burst(
+            ParticleEmitter::burst(6)
+                .with_cone(velocity.0, 2.0)
+                .with_speed(30.0, 70.0)
+                .with_lifetime(0.1, 0.3)
+                .with_color(ParticleColor::fade(Color::hsl(50.0, 1.0, 0.8))),
+            transform.translation(),
+        ));
+    }
+}
+
+fn apply_bullet_bounces(
+    mut commands: Commands,
+    bullets: Query<(
+        Entity,
+        &PendingBounce,
+        &mut LinearVelocity,
+        &mut BulletBounces,
+    )>,
+) {
+    let mut rng = rand::rng();
+    for (entity, bounce, mut velocity, mut bounces) in bullets {
+        let max_angle = bounce.deflection.to_radians();
+        let angle = if max_angle > 0.0 {
+            rng.random_range(-max_angle ..= max_angle)
+        } else {
+            0.0
+        };
+        velocity.0 = Vec2::from_angle(angle).rotate(velocity.0) * bounce.speed_gain;
+        bounces.0 += 1;
+        commands.entity(entity).remove::<PendingBounce>();
+    }
+}
+
+fn draw_trajectory_preview(
+    mut gizmos: Gizmos,
+    assets: Res<BulletAssets>,
+    spatial_query: SpatialQuery,
+    spawners: Query<
+        (
+            &Transform,
+            &AimDirection,
+            &EquippedBulletType,
+            Option<&charge::ShotCharge>,
+        ),
+        (With<BulletSpawner>, With<BulletAvailable>),
+    >,
+) {
+    const MAX_SEGMENTS: usize = 4;
+
+    let filter = SpatialQueryFilter::from_mask(CollisionTag::Solid);
+
+    for (transform, aim, equipped, charge) in spawners {
+        let Some(dir) = &aim.0 else {
+            continue;
+        };
+
+        let Ok(mut direction) = Dir2::new(dir.vec()) else {
+            continue;
+        };
+        let stats = equipped.0.stats();
+        let mut origin = transform.translation.truncate() + direction * assets.spawn_offset;
+        // Same speed the bullet would be fired with right now, see `fire_bullet`
+        let speed = charge::charge_multiplier(
+            charge.map(charge::ShotCharge::amount).unwrap_or_default(),
+            charge::MAX_SPEED_MULTIPLIER,
+        );
+        let mut remaining = stats.speed * speed * stats.duration.as_secs_f32();
+
+        for segment in 0 .. MAX_SEGMENTS {
+            let alpha = 0.6 * (1.0 - segment as f32 / MAX_SEGMENTS as f32);
+            let color = Color::hsla(0.0, 0.0, 1.0, alpha);
+
+            let Some(hit) = spatial_query.cast_ray(origin, direction, remaining, true, &filter)
+            else {
+                gizmos.line_2d(origin, origin + direction * remaining, color);
+                break;
+            };
+
+            let point = origin + direction * hit.distance;
+            gizmos.line_2d(origin, point, color);
+
+            remaining -= hit.distance;
+            let reflected = direction.reflect(hit.normal);
+            match Dir2::new(reflected) {
+                Ok(reflected) if hit.distance > 0.0 && remaining > 0.0 => {
+                    direction = reflected;
+                    origin = point + direction * 0.1;
+                },
+                _ => break,
+            }
+        }
+    }
+}
+
+fn render_equipped_bullet_type(
+    spawners: Query<&EquippedBulletType, Changed<EquippedBulletType>>,
+    query: Query<&mut TextSpan, With<BulletTypeValueUi>>,
+) {
+    let Some(equipped) = spawners.iter().next() else {
+        return;
+    };
+    for mut ui_text in query {
+        ui_text.0 = equipped.0.name().to_string();
+    }
+}
+
+fn apply_bullet_movement_modifier(
+    spawners: Query<(&mut MovementModifiers, Has<BulletAvailable>), With<BulletSpawner>>,
+) {
+    for (mut modifiers, has_bullet) in spawners {
+        let multiplier = if has_bullet {
+            CARRYING_BULLET_SPEED
+        } else {
+            WITHOUT_BULLET_SPEED
+        };
+
+        let unchanged = modifiers
+            .get(ModifierSource::Bullet)
+            .is_some_and(|modifier| modifier.multiplier == multiplier);
+        if !unchanged {
+            modifiers.set(ModifierSource::Bullet, multiplier, None);
+        }
+    }
+}
+
+/// Continuous trail behind a flying bullet.
+fn trail_particles(hue: f32) -> ParticleEmitter {
+    ParticleEmitter::continuous(30.0)
+        .with_speed(0.0, 8.0)
+        .with_lifetime(0.2, 0.4)
+        .with_damping(1.0)
+        .with_size(1.0)
+        .with_color(ParticleColor {
+            hue_shift: 120.0,
+            ..ParticleColor::fade(Color::hsl(hue + 20.0, 0.9, 0.5))
+        })
+}
@@ -0,0 +1,163 @@
+//! Bullet type pickups dropped by bigger enemies, switching the collector's bullet type.
+
+use super::{BulletAssets, BulletSpawner, BulletType, EquippedBulletType};
+use crate::{
+    AppSystems, GameplaySet,
+    audio::sound_effect,
+    game::{
+        enemy::{Enemy, EnemyVariant},
+        health::EntityDied,
+        movement::{ModifierSource, MovementModifiers},
+        particles::{ParticleColor, ParticleEmitter, particle_burst},
+        util::CollisionTag,
+        visuals::{AnimationDirection, HueAnimation, SetSpriteColor, VisualAnimation},
+    },
+    screens::Screen,
+};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_aseprite_ultra::prelude::{Animation, AseAnimation};
+use rand::Rng;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(drop_bullet_pickup);
+
+    app.add_systems(
+        Update,
+        update_bullet_pickups
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+}
+
+const DROP_CHANCE: f64 = 0.5;
+const PICKUP_LIFETIME: Duration = Duration::from_secs(10);
+/// Pickups start blinking when this much lifetime is left.
+const PICKUP_BLINK_DURATION: Duration = Duration::from_secs(3);
+const PICKUP_BLINK_INTERVAL: f32 = 0.15;
+/// Short speed boost when collecting a pickup.
+const PICKUP_SPEED_BOOST: f32 = 1.3;
+const PICKUP_SPEED_BOOST_DURATION: Duration = Duration::from_secs(3);
+
+#[derive(Component, Reflect, Clone, Copy)]
+#[reflect(Component)]
+#[require(
+    Name::new("BulletPickup"),
+    DespawnOnExit::<_>(Screen::Gameplay),
+    Sprite::default(),
+    Sensor,
+    Collider::circle(6.0),
+    CollisionLayers::new(CollisionTag::Collectable, CollisionTag::Player),
+    CollisionEventsEnabled,
+    SetSpriteColor(Color::hsl(0.0, 0.9, 0.4)),
+)]
+pub struct BulletPickup(pub BulletType);
+
+#[derive(Component)]
+struct PickupTimer(Timer);
+
+fn drop_bullet_pickup(
+    event: On<EntityDied>,
+    mut commands: Commands,
+    assets: Res<BulletAssets>,
+    enemies: Query<(&GlobalTransform, &EnemyVariant), With<Enemy>>,
+) {
+    let Ok((transform, variant)) = enemies.get(event.entity) else {
+        return;
+    };
+    let mut rng = rand::rng();
+    let drops = match variant {
+        EnemyVariant::Basic => false,
+        EnemyVariant::Bigger => rng.random_bool(DROP_CHANCE),
+        EnemyVariant::Boss => true,
+    };
+    if !drops {
+        return;
+    }
+
+    // Any type but the standard one
+    let bullet_type = BulletType::ALL[rng.random_range(1 .. BulletType::ALL.len())];
+
+    commands
+        .spawn((
+            BulletPickup(bullet_type),
+            PickupTimer(Timer::new(PICKUP_LIFETIME, TimerMode::Once)),
+            AseAnimation {
+                aseprite:  assets.spritesheet.clone(),
+                animation: Animation::tag("idle"),
+            },
+            HueAnimation(VisualAnimation {
+                range: Some(bullet_type.stats().hue_range),
+                period: 1.0,
+                direction: AnimationDirection::Boomerang,
+                ..default()
+            }),
+            Transform::from_translation(transform.translation())
+                .with_scale(Vec3::splat(bullet_type.stats().scale())),
+        ))
+        .observe(handle_collect_pickup);
+}
+
+fn handle_collect_pickup(
+    trigger: On<CollisionStart>,
+    mut commands: Commands,
+    assets: Res<BulletAssets>,
+    pickups: Query<(&BulletPickup, &GlobalTransform)>,
+    mut spawners: Query<
+        (&mut EquippedBulletType, Option<&mut MovementModifiers>),
+        With<BulletSpawner>,
+    >,
+) {
+    let pickup = trigger.collider1;
+    let spawner = trigger.collider2;
+
+    if let Ok((BulletPickup(bullet_type), transform)) = pickups.get(pickup)
+        && let Ok((mut equipped, modifiers)) = spawners.get_mut(spawner)
+    {
+        equipped.0 = *bullet_type;
+        if let Some(mut modifiers) = modifiers {
+            modifiers.set(
+                ModifierSource::Pickup,
+                PICKUP_SPEED_BOOST,
+                Some(PICKUP_SPEED_BOOST_DURATION),
+            );
+        }
+
+        commands.spawn(particle_burst(
+            ParticleEmitter::burst(16)
+                .with_speed(40.0, 80.0)
+                .with_lifetime(0.2, 0.5)
+                .with_color(ParticleColor {
+                    hue_shift: 90.0,
+                    ..ParticleColor::fade(Color::hsl(bullet_type.stats().hue_range.0, 0.9, 0.7))
+                }),
+            transform.translation(),
+        ));
+        commands.spawn(sound_effect(assets.sfx_collect.clone()));
+        commands.entity(pickup).despawn();
+    }
+}
+
+fn update_bullet_pickups(
+    mut commands: Commands,
+    time: Res<Time>,
+    pickups: Query<(Entity, &mut PickupTimer, &mut Visibility), With<BulletPickup>>,
+) {
+    for (entity, mut timer, mut visibility) in pickups {
+        timer.0.tick(time.delta());
+        if timer.0.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let remaining = timer.0.remaining();
+        *visibility = if remaining < PICKUP_BLINK_DURATION
+            && ((remaining.as_secs_f32() / PICKUP_BLINK_INTERVAL) as u32).is_multiple_of(2)
+        {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
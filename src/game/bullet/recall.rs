@@ -0,0 +1,196 @@
+//! Recall ability, pulling a fired bullet back to its spawner and damaging enemies on the way.
+
+use super::{
+    Bullet, BulletAvailable, BulletBounces, BulletHit, BulletProfile, BulletSpawner, BulletStats,
+    behaviors::{BulletShard, ENEMY_RADIUS},
+};
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        enemy::Enemy,
+        health::{DamageCause, Dead},
+        util::CollisionTag,
+    },
+    input::{PlayerAction, action_just_pressed},
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RecallSettings>();
+
+    app.add_systems(
+        Update,
+        tick_recall_cooldowns
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            start_recall.run_if(action_just_pressed(PlayerAction::Recall)),
+            steer_recalled_bullets,
+            handle_recalled_bullet_hits,
+            render_recall_cooldown,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Tuning of the bullet recall ability.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct RecallSettings {
+    /// Speed the recalled bullet flies back to its spawner with
+    pub speed:    Scalar,
+    pub cooldown: Duration,
+}
+
+impl Default for RecallSettings {
+    fn default() -> Self {
+        Self {
+            speed:    260.0,
+            cooldown: Duration::from_secs(4),
+        }
+    }
+}
+
+/// Time until a [`BulletSpawner`] can recall its bullet again.
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct RecallCooldown(pub Timer);
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct RecallValueUi;
+
+/// Bullet flying back to its spawner, ignoring solids.
+#[derive(Component, Default)]
+pub(super) struct Recalling {
+    hit: Vec<Entity>,
+}
+
+fn tick_recall_cooldowns(time: Res<Time>, cooldowns: Query<&mut RecallCooldown>) {
+    for mut cooldown in cooldowns {
+        cooldown.0.tick(time.delta());
+    }
+}
+
+fn start_recall(
+    mut commands: Commands,
+    settings: Res<RecallSettings>,
+    mut spawners: Query<
+        &mut RecallCooldown,
+        (With<BulletSpawner>, Without<BulletAvailable>, Without<Dead>),
+    >,
+    bullets: Query<
+        (Entity, &mut CollisionLayers),
+        (With<Bullet>, Without<BulletShard>, Without<Recalling>),
+    >,
+) {
+    let Some(mut cooldown) = spawners
+        .iter_mut()
+        .find(|cooldown| cooldown.0.is_finished())
+    else {
+        return;
+    };
+
+    let mut recalled = false;
+    for (bullet, mut collision_layers) in bullets {
+        collision_layers.memberships = CollisionTag::Collectable.into();
+        collision_layers.filters = CollisionTag::Player.into();
+        commands
+            .entity(bullet)
+            .insert((Recalling::default(), LinearDamping(0.0)));
+        recalled = true;
+    }
+
+    if recalled {
+        cooldown.0 = Timer::new(settings.cooldown, TimerMode::Once);
+    }
+}
+
+fn steer_recalled_bullets(
+    settings: Res<RecallSettings>,
+    bullets: Query<(&GlobalTransform, &mut LinearVelocity), With<Recalling>>,
+    spawners: Query<&GlobalTransform, With<BulletSpawner>>,
+) {
+    for (transform, mut velocity) in bullets {
+        let position = transform.translation().truncate();
+        let Some(target) = spawners
+            .iter()
+            .map(|spawner| spawner.translation().truncate())
+            .min_by(|a, b| {
+                a.distance_squared(position)
+                    .total_cmp(&b.distance_squared(position))
+            })
+        else {
+            continue;
+        };
+
+        velocity.0 = (target - position).normalize_or_zero() * settings.speed;
+    }
+}
+
+fn handle_recalled_bullet_hits(
+    mut commands: Commands,
+    bullets: Query<(
+        Entity,
+        &GlobalTransform,
+        &LinearVelocity,
+        &BulletStats,
+        &BulletProfile,
+        &BulletBounces,
+        &mut Recalling,
+    )>,
+    enemies: Query<(Entity, &GlobalTransform), (With<Enemy>, Without<Dead>)>,
+) {
+    for (bullet, transform, velocity, stats, profile, bounces, mut recalling) in bullets {
+        let position = transform.translation().truncate();
+        for (enemy, enemy_transform) in enemies {
+            if recalling.hit.contains(&enemy) {
+                continue;
+            }
+
+            let reach = stats.radius + ENEMY_RADIUS * enemy_transform.scale().x;
+            if position.distance(enemy_transform.translation().truncate()) > reach {
+                continue;
+            }
+
+            recalling.hit.push(enemy);
+            commands.trigger(BulletHit {
+                enemy,
+                bullet,
+                direction: velocity.normalize_or_zero(),
+                damage: profile.damage_after_bounces(bounces.0),
+                knockback: stats.enemy_knockback,
+                cause: DamageCause::Bullet,
+            });
+        }
+    }
+}
+
+fn render_recall_cooldown(
+    spawners: Query<&RecallCooldown, With<BulletSpawner>>,
+    query: Query<&mut TextSpan, With<RecallValueUi>>,
+) {
+    let Some(cooldown) = spawners.iter().next() else {
+        return;
+    };
+
+    let text = if cooldown.0.is_finished() {
+        "Ready".to_string()
+    } else {
+        format!("{:.1}s", cooldown.0.remaining_secs())
+    };
+
+    for mut ui_text in query {
+        if ui_text.0 != text {
+            ui_text.0 = text.clone();
+        }
+    }
+}
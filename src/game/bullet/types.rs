@@ -0,0 +1,150 @@
+//! Alternate bullet types with their own stats, colors, sizes and collision behavior.
+
+use super::BulletProfile;
+use avian2d::math::Scalar;
+use bevy::prelude::*;
+use std::time::Duration;
+
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[reflect(Component)]
+pub enum BulletType {
+    #[default]
+    Standard,
+    /// Big and slow, hits harder and knocks enemies back further
+    Heavy,
+    /// Passes through enemies, damaging each one once
+    Piercing,
+    /// Splits into two shards on the first enemy hit
+    Splitting,
+    /// Explodes when it stops flying, damaging all enemies around it
+    Explosive,
+    /// Returns to its spawner after flying
+    Boomerang,
+}
+
+impl BulletType {
+    pub const ALL: [Self; 6] = [
+        Self::Standard,
+        Self::Heavy,
+        Self::Piercing,
+        Self::Splitting,
+        Self::Explosive,
+        Self::Boomerang,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::Heavy => "Heavy",
+            Self::Piercing => "Piercing",
+            Self::Splitting => "Splitting",
+            Self::Explosive => "Explosive",
+            Self::Boomerang => "Boomerang",
+        }
+    }
+
+    pub fn stats(&self) -> BulletStats {
+        let standard = BulletStats::default();
+        match self {
+            Self::Standard | Self::Splitting => standard,
+            Self::Heavy => BulletStats {
+                speed:            160.0,
+                duration:         Duration::from_millis(600),
+                velocity_damping: 3.0,
+                player_knockback: 600.0,
+                enemy_knockback:  2.0,
+                radius:           9.0,
+                mass:             2.0,
+                restitution:      0.5,
+                hue_range:        (200.0, 260.0),
+            },
+            Self::Piercing => BulletStats {
+                speed: 260.0,
+                duration: Duration::from_millis(400),
+                player_knockback: 300.0,
+                enemy_knockback: 0.5,
+                radius: 5.0,
+                mass: 0.3,
+                hue_range: (140.0, 190.0),
+                ..standard
+            },
+            Self::Explosive => BulletStats {
+                speed: 180.0,
+                duration: Duration::from_millis(700),
+                velocity_damping: 3.0,
+                radius: 7.0,
+                mass: 0.8,
+                restitution: 0.6,
+                hue_range: (20.0, 50.0),
+                ..standard
+            },
+            Self::Boomerang => BulletStats {
+                speed: 220.0,
+                duration: Duration::from_millis(450),
+                player_knockback: 300.0,
+                restitution: 0.9,
+                hue_range: (90.0, 130.0),
+                ..standard
+            },
+        }
+    }
+
+    pub fn profile(&self) -> BulletProfile {
+        let standard = BulletProfile::default();
+        match self {
+            Self::Standard | Self::Splitting | Self::Explosive | Self::Boomerang => standard,
+            Self::Heavy => BulletProfile {
+                enemy_deflection: 10.0,
+                enemy_speed_gain: 1.0,
+                max_speed: 200.0,
+                damage: 2,
+                ..standard
+            },
+            Self::Piercing => BulletProfile {
+                enemy_speed_gain: 1.0,
+                max_speed: 320.0,
+                ..standard
+            },
+        }
+    }
+}
+
+/// Stats of a fired bullet, determined by its [`BulletType`].
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub struct BulletStats {
+    pub speed:            Scalar,
+    /// Time the bullet flies before it can be collected again
+    pub duration:         Duration,
+    pub velocity_damping: Scalar,
+    pub player_knockback: Scalar,
+    /// Multiplier of the enemy's own knockback when hit
+    pub enemy_knockback:  Scalar,
+    pub radius:           Scalar,
+    pub mass:             Scalar,
+    pub restitution:      Scalar,
+    pub hue_range:        (f32, f32),
+}
+
+impl BulletStats {
+    /// Sprite and collider scale, relative to the standard bullet's radius.
+    pub fn scale(&self) -> f32 {
+        self.radius / BulletStats::default().radius
+    }
+}
+
+impl Default for BulletStats {
+    fn default() -> Self {
+        Self {
+            speed:            200.0,
+            duration:         Duration::from_millis(500),
+            velocity_damping: 2.0,
+            player_knockback: 400.0,
+            enemy_knockback:  1.0,
+            radius:           6.5,
+            mass:             0.5,
+            restitution:      0.8,
+            hue_range:        (0.0, 70.0),
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Daily challenge: a seed and set of modifiers derived from the current date,
+//! with locked settings and a local leaderboard file per day.
+
+#[cfg(not(target_family = "wasm"))]
+use crate::game::statistics::SAVE_DIR;
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        bullet::{
+            Bullet, BulletType, ChargeShotEnabled, RecallSettings, RunBulletType,
+            TrajectoryPreviewEnabled,
+        },
+        difficulty::{DifficultyPreset, DifficultySettings, RunDifficulty},
+        enemy::EnemyVariant,
+        game_mode::{GameMode, RunGameMode},
+        mutators::RunMutators,
+        score::Score,
+        survival_timer::SurvivalTimer,
+    },
+    game_state::GameOver,
+    screens::Screen,
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng, seq::IndexedRandom};
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunDailyChallenge>()
+        .init_resource::<LockedSettings>();
+
+    app.add_observer(begin_daily_challenge);
+
+    app.add_systems(
+        OnEnter(GameOver(true)),
+        record_daily_result.run_if(is_daily_run),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), end_daily_challenge);
+
+    app.add_systems(
+        Update,
+        speed_up_bullets
+            .run_if(has_modifier(DailyModifier::FastBullet))
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+const MODIFIERS_PER_DAY: usize = 2;
+const FAST_BULLET_SPEED: Scalar = 1.5;
+
+/// Directory inside [`SAVE_DIR`] the per-day leaderboard files are written to.
+#[cfg(not(target_family = "wasm"))]
+const LEADERBOARD_DIR: &str = "daily";
+#[cfg(not(target_family = "wasm"))]
+const LEADERBOARD_SIZE: usize = 10;
+
+/// Rule changes of a daily challenge.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DailyModifier {
+    /// All basic enemies are replaced with bigger ones
+    OnlyBigger,
+    FastBullet,
+    LowHealth,
+    FastEnemies,
+    ToughEnemies,
+    BigWaves,
+}
+
+impl DailyModifier {
+    pub const ALL: [Self; 6] = [
+        Self::OnlyBigger,
+        Self::FastBullet,
+        Self::LowHealth,
+        Self::FastEnemies,
+        Self::ToughEnemies,
+        Self::BigWaves,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::OnlyBigger => "Only bigger enemies",
+            Self::FastBullet => "Faster bullet",
+            Self::LowHealth => "Half health",
+            Self::FastEnemies => "Faster enemies",
+            Self::ToughEnemies => "Tougher enemies",
+            Self::BigWaves => "Bigger waves",
+        }
+    }
+
+    fn difficulty(&self) -> DifficultySettings {
+        match self {
+            Self::LowHealth => DifficultySettings {
+                player_health: 0.5,
+                ..default()
+            },
+            Self::FastEnemies => DifficultySettings {
+                enemy_speed: 1.3,
+                ..default()
+            },
+            Self::ToughEnemies => DifficultySettings {
+                enemy_health: 2.0,
+                ..default()
+            },
+            Self::BigWaves => DifficultySettings {
+                wave_size: 1.5,
+                ..default()
+            },
+            Self::OnlyBigger | Self::FastBullet => DifficultySettings::default(),
+        }
+    }
+}
+
+/// Calendar date in UTC.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Date {
+    pub year:  i64,
+    pub month: u32,
+    pub day:   u32,
+}
+
+impl Date {
+    /// Current date from the system clock, which panics on the web.
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Self::from_days_since_epoch((secs / 86_400) as i64)
+    }
+
+    /// Civil date from days since 1970-01-01, see <http://howardhinnant.github.io/date_algorithms.html>.
+    fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        Self { year, month, day }
+    }
+
+    fn seed(&self) -> u64 {
+        let key = self.year as u64 * 10_000 + self.month as u64 * 100 + self.day as u64;
+        key.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct DailyChallenge {
+    pub date:        Date,
+    pub seed:        u64,
+    pub modifiers:   Vec<DailyModifier>,
+    pub bullet_type: BulletType,
+}
+
+impl DailyChallenge {
+    pub fn today() -> Self {
+        Self::for_date(Date::today())
+    }
+
+    fn for_date(date: Date) -> Self {
+        let seed = date.seed();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let modifiers = DailyModifier::ALL
+            .choose_multiple(&mut rng, MODIFIERS_PER_DAY)
+            .copied()
+            .collect();
+        let bullet_type = *BulletType::ALL
+            .choose(&mut rng)
+            .unwrap_or(&BulletType::default());
+        Self {
+            date,
+            seed,
+            modifiers,
+            bullet_type,
+        }
+    }
+
+    pub fn has(&self, modifier: DailyModifier) -> bool {
+        self.modifiers.contains(&modifier)
+    }
+
+    /// Combined difficulty multipliers of all modifiers.
+    pub fn difficulty(&self) -> DifficultySettings {
+        self.modifiers
+            .iter()
+            .fold(DifficultySettings::default(), |settings, modifier| {
+                settings * modifier.difficulty()
+            })
+    }
+
+    /// Variant to spawn instead of the given one.
+    pub fn enemy_variant(&self, variant: EnemyVariant) -> EnemyVariant {
+        match variant {
+            EnemyVariant::Basic if self.has(DailyModifier::OnlyBigger) => EnemyVariant::Bigger,
+            variant => variant,
+        }
+    }
+}
+
+/// The daily challenge of the current run, if the run is one.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct RunDailyChallenge(pub Option<DailyChallenge>);
+
+/// Trigger to set up the next run as today's daily challenge, before starting it.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct StartDailyChallenge;
+
+/// Player's own run setup and settings, put aside while the daily challenge locks them.
+#[derive(Resource, Default)]
+struct LockedSettings(Option<PlayerSettings>);
+
+struct PlayerSettings {
+    difficulty:         RunDifficulty,
+    game_mode:          RunGameMode,
+    bullet_type:        RunBulletType,
+    trajectory_preview: TrajectoryPreviewEnabled,
+    charge_shot:        ChargeShotEnabled,
+    recall:             RecallSettings,
+    mutators:           RunMutators,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DailyResult {
+    pub score:    u32,
+    pub survived: Duration,
+}
+
+pub fn is_daily_run(daily: Res<RunDailyChallenge>) -> bool {
+    daily.0.is_some()
+}
+
+fn has_modifier(modifier: DailyModifier) -> impl Fn(Res<RunDailyChallenge>) -> bool {
+    move |daily: Res<RunDailyChallenge>| daily.0.as_ref().is_some_and(|daily| daily.has(modifier))
+}
+
+fn begin_daily_challenge(
+    _: On<StartDailyChallenge>,
+    mut daily: ResMut<RunDailyChallenge>,
+    mut locked: ResMut<LockedSettings>,
+    mut difficulty: ResMut<RunDifficulty>,
+    mut game_mode: ResMut<RunGameMode>,
+    mut bullet_type: ResMut<RunBulletType>,
+    mut trajectory_preview: ResMut<TrajectoryPreviewEnabled>,
+    mut charge_shot: ResMut<ChargeShotEnabled>,
+    mut recall: ResMut<RecallSettings>,
+    mut mutators: ResMut<RunMutators>,
+) {
+    let challenge = DailyChallenge::today();
+
+    // Everyone plays with the same settings
+    if locked.0.is_none() {
+        locked.0 = Some(PlayerSettings {
+            difficulty:         *difficulty,
+            game_mode:          *game_mode,
+            bullet_type:        *bullet_type,
+            trajectory_preview: *trajectory_preview,
+            charge_shot:        *charge_shot,
+            recall:             recall.clone(),
+            mutators:           *mutators,
+        });
+    }
+
+    *difficulty = RunDifficulty {
+        preset:    DifficultyPreset::Normal,
+        adaptive:  false,
+        modifiers: challenge.difficulty(),
+    };
+    game_mode.0 = GameMode::Endless;
+    bullet_type.0 = challenge.bullet_type;
+    *trajectory_preview = TrajectoryPreviewEnabled::default();
+    *charge_shot = ChargeShotEnabled::default();
+    *recall = RecallSettings::default();
+    *mutators = RunMutators::default();
+
+    daily.0 = Some(challenge);
+}
+
+fn end_daily_challenge(
+    mut daily: ResMut<RunDailyChallenge>,
+    mut locked: ResMut<LockedSettings>,
+    mut difficulty: ResMut<RunDifficulty>,
+    mut game_mode: ResMut<RunGameMode>,
+    mut bullet_type: ResMut<RunBulletType>,
+    mut trajectory_preview: ResMut<TrajectoryPreviewEnabled>,
+    mut charge_shot: ResMut<ChargeShotEnabled>,
+    mut recall: ResMut<RecallSettings>,
+    mut mutators: ResMut<RunMutators>,
+) {
+    if daily.0.take().is_none() {
+        return;
+    }
+    match locked.0.take() {
+        Some(prev) => {
+            *difficulty = prev.difficulty;
+            *game_mode = prev.game_mode;
+            *bullet_type = prev.bullet_type;
+            *trajectory_preview = prev.trajectory_preview;
+            *charge_shot = prev.charge_shot;
+            *recall = prev.recall;
+            *mutators = prev.mutators;
+        },
+        None => difficulty.modifiers = DifficultySettings::default(),
+    }
+}
+
+fn speed_up_bullets(bullets: Query<(&mut LinearVelocity, &mut MaxLinearSpeed), Added<Bullet>>) {
+    for (mut velocity, mut max_speed) in bullets {
+        velocity.0 *= FAST_BULLET_SPEED;
+        max_speed.0 *= FAST_BULLET_SPEED;
+    }
+}
+
+fn record_daily_result(
+    daily: Res<RunDailyChallenge>,
+    score: Res<Score>,
+    survival_time: Res<SurvivalTimer>,
+) {
+    let Some(daily) = &daily.0 else {
+        return;
+    };
+    let result = DailyResult {
+        score:    score.0,
+        survived: survival_time.0.elapsed(),
+    };
+    if let Err(err) = save_result(&daily.date, result) {
+        warn!("Failed to save daily challenge result: {err}");
+    }
+}
+
+/// Today's best results, best first.
+#[cfg(not(target_family = "wasm"))]
+pub fn load_leaderboard(date: &Date) -> Vec<DailyResult> {
+    let Ok(content) = std::fs::read_to_string(leaderboard_path(date)) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| {
+            let (score, survived) = line.split_once(' ')?;
+            Some(DailyResult {
+                score:    score.parse().ok()?,
+                survived: Duration::from_millis(survived.parse().ok()?),
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_family = "wasm")]
+pub fn load_leaderboard(_date: &Date) -> Vec<DailyResult> {
+    Vec::new()
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn save_result(date: &Date, result: DailyResult) -> std::io::Result<()> {
+    let mut leaderboard = load_leaderboard(date);
+    let index = leaderboard
+        .iter()
+        .position(|other| result.score > other.score)
+        .unwrap_or(leaderboard.len());
+    leaderboard.insert(index, result);
+    leaderboard.truncate(LEADERBOARD_SIZE);
+
+    let content = leaderboard
+        .iter()
+        .map(|result| format!("{} {}\n", result.score, result.survived.as_millis()))
+        .collect::<String>();
+    std::fs::create_dir_all(std::path::Path::new(SAVE_DIR).join(LEADERBOARD_DIR))?;
+    std::fs::write(leaderboard_path(date), content)
+}
+
+/// No file system on the web, results aren't kept.
+#[cfg(target_family = "wasm")]
+fn save_result(_date: &Date, _result: DailyResult) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn leaderboard_path(date: &Date) -> std::path::PathBuf {
+    std::path::Path::new(SAVE_DIR)
+        .join(LEADERBOARD_DIR)
+        .join(format!("{date}.txt"))
+}
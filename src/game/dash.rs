@@ -0,0 +1,150 @@
+//! Short invulnerable dash for the player to escape enemies.
+
+use crate::{
+    AppSystems,
+    game::{
+        health::Dead,
+        movement::{MovementController, Stunned, WalkDirection},
+        particles::{ParticleColor, ParticleEmitter},
+    },
+    game_state::{ActiveGameplaySet, GameplaySet},
+    input::{ActionState, PlayerAction, action_just_pressed},
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        update_dashing
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+
+    app.add_systems(
+        Update,
+        start_dash
+            .run_if(action_just_pressed(PlayerAction::Dash))
+            .in_set(AppSystems::Update)
+            .in_set(ActiveGameplaySet),
+    );
+}
+
+/// Allows dashing with [`PlayerAction::Dash`].
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+#[require(MovementController, DashCooldown)]
+pub struct DashController {
+    pub speed:    Scalar,
+    pub duration: Duration,
+    pub cooldown: Duration,
+    /// Linear damping while dashing, replacing the entity's own damping
+    pub damping:  Scalar,
+}
+
+impl Default for DashController {
+    fn default() -> Self {
+        Self {
+            speed:    420.0,
+            duration: Duration::from_millis(180),
+            cooldown: Duration::from_secs(1),
+            damping:  2.0,
+        }
+    }
+}
+
+/// Time until the next dash is possible.
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct DashCooldown(pub Timer);
+
+/// Currently dashing, movement controls are disabled and enemy contact deals no damage.
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
+pub struct Dashing {
+    timer:           Timer,
+    /// Linear damping to restore once the dash is over
+    restore_damping: Scalar,
+}
+
+fn start_dash(
+    mut commands: Commands,
+    action_state: Res<ActionState<PlayerAction>>,
+    controllers: Query<
+        (
+            Entity,
+            &DashController,
+            &DashCooldown,
+            &WalkDirection,
+            &mut LinearVelocity,
+            Option<&LinearDamping>,
+        ),
+        (Without<Dashing>, Without<Stunned>, Without<Dead>),
+    >,
+) {
+    let input = action_state
+        .clamped_axis_pair(&PlayerAction::Move)
+        .normalize_or_zero();
+
+    for (entity, dash, cooldown, walk_direction, mut velocity, damping) in controllers {
+        if !cooldown.0.is_finished() {
+            continue;
+        }
+
+        // Dash where the player is steering, or keep going the current way
+        let direction = if input != Vec2::ZERO {
+            input
+        } else if let Some(walk) = &walk_direction.0 {
+            walk.vec()
+        } else {
+            velocity.normalize_or_zero()
+        };
+        if direction == Vec2::ZERO {
+            continue;
+        }
+
+        velocity.0 = direction * dash.speed;
+
+        commands.entity(entity).insert((
+            Dashing {
+                timer:           Timer::new(dash.duration, TimerMode::Once),
+                restore_damping: damping.map(|damping| damping.0).unwrap_or_default(),
+            },
+            LinearDamping(dash.damping),
+            ParticleEmitter::continuous(80.0)
+                .with_speed(0.0, 10.0)
+                .with_lifetime(0.15, 0.3)
+                .with_size(2.0)
+                .with_color(ParticleColor::fade(Color::hsla(0.0, 0.0, 1.0, 0.6))),
+        ));
+    }
+}
+
+fn update_dashing(
+    mut commands: Commands,
+    time: Res<Time>,
+    controllers: Query<(
+        Entity,
+        &DashController,
+        &mut DashCooldown,
+        Option<&mut Dashing>,
+    )>,
+) {
+    for (entity, dash, mut cooldown, dashing) in controllers {
+        cooldown.0.tick(time.delta());
+
+        let Some(mut dashing) = dashing else {
+            continue;
+        };
+
+        dashing.timer.tick(time.delta());
+        if dashing.timer.is_finished() {
+            cooldown.0 = Timer::new(dash.cooldown, TimerMode::Once);
+            commands
+                .entity(entity)
+                .remove::<(Dashing, ParticleEmitter)>()
+                .insert(LinearDamping(dashing.restore_damping));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Per-entity death behaviors, played out once an entity is [`Dead`] and until it is despawned.
+
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        health::{Alive, Dead},
+        particles::{ParticleColor, ParticleEmitter, particle_burst},
+        visuals::HueAnimationState,
+    },
+    screens::Screen,
+};
+use bevy::prelude::*;
+use bevy_aseprite_ultra::prelude::{AnimationRepeat, AseAnimation};
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            (start_dying, revive_dying, update_dying).chain(),
+            update_death_decals,
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// How an entity dies after becoming [`Dead`].
+/// Dead entities without this component use the default behavior of shrinking away.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct DeathBehavior {
+    /// Aseprite animation tag to play once while dying
+    pub animation: Option<String>,
+    /// Shrink down to nothing while dying
+    pub shrink:    bool,
+    /// Time spent dying before being despawned
+    pub duration:  Duration,
+    /// Amount of particles to burst on death, tinted with the entity's current hue
+    pub particles: u32,
+    /// Leave behind a decal of the last frame, fading out over this duration
+    pub decal:     Option<Duration>,
+}
+
+impl Default for DeathBehavior {
+    fn default() -> Self {
+        Self {
+            animation: None,
+            shrink:    true,
+            duration:  Duration::from_secs(1),
+            particles: 0,
+            decal:     None,
+        }
+    }
+}
+
+#[derive(Component)]
+struct Dying {
+    timer:       Timer,
+    start_scale: Vec3,
+}
+
+#[derive(Component)]
+struct DeathDecal(Timer);
+
+fn start_dying(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            Option<&DeathBehavior>,
+            &Transform,
+            &GlobalTransform,
+            Option<&mut AseAnimation>,
+            Option<&Sprite>,
+            Option<&HueAnimationState>,
+        ),
+        (Added<Dead>, Without<Dying>),
+    >,
+) {
+    for (entity, behavior, transform, global_transform, ase, sprite, hue) in query {
+        let behavior = behavior.cloned().unwrap_or_default();
+
+        if let (Some(tag), Some(mut ase)) = (&behavior.animation, ase) {
+            ase.animation.play(tag.as_str(), AnimationRepeat::Count(1));
+        }
+
+        if behavior.particles > 0 {
+            commands.spawn(particle_burst(
+                ParticleEmitter::burst(behavior.particles)
+                    .with_speed(20.0, 80.0)
+                    .with_color(ParticleColor {
+                        hue_shift: 60.0,
+                        ..ParticleColor::fade(death_color(sprite, hue))
+                    }),
+                global_transform.translation(),
+            ));
+        }
+
+        commands.entity(entity).insert(Dying {
+            timer:       Timer::new(behavior.duration, TimerMode::Once),
+            start_scale: transform.scale,
+        });
+    }
+}
+
+fn revive_dying(
+    mut commands: Commands,
+    query: Query<(Entity, &Dying, &mut Transform), Added<Alive>>,
+) {
+    for (entity, dying, mut transform) in query {
+        transform.scale = dying.start_scale;
+        commands.entity(entity).remove::<Dying>();
+    }
+}
+
+fn update_dying(
+    mut commands: Commands,
+    time: Res<Time>,
+    query: Query<
+        (
+            Entity,
+            &mut Dying,
+            Option<&DeathBehavior>,
+            &mut Transform,
+            &GlobalTransform,
+            Option<&Sprite>,
+        ),
+        With<Dead>,
+    >,
+) {
+    for (entity, mut dying, behavior, mut transform, global_transform, sprite) in query {
+        dying.timer.tick(time.delta());
+
+        let shrink = behavior.map(|b| b.shrink).unwrap_or(true);
+        if shrink {
+            let scale = dying.start_scale.truncate() * dying.timer.fraction_remaining();
+            transform.scale = scale.extend(transform.scale.z);
+        }
+
+        if !dying.timer.is_finished() {
+            continue;
+        }
+
+        if let (Some(duration), Some(sprite)) = (behavior.and_then(|b| b.decal), sprite) {
+            // Shrinking left the entity at almost zero scale, the decal keeps its original size
+            let decal_transform = Transform {
+                scale: dying.start_scale,
+                ..global_transform.compute_transform()
+            };
+            commands.spawn(death_decal(sprite, decal_transform, duration));
+        }
+
+        commands.entity(entity).despawn();
+    }
+}
+
+fn update_death_decals(
+    mut commands: Commands,
+    time: Res<Time>,
+    query: Query<(Entity, &mut DeathDecal, &mut Sprite)>,
+) {
+    const ALPHA: f32 = 0.5;
+
+    for (entity, mut decal, mut sprite) in query {
+        decal.0.tick(time.delta());
+        sprite.color.set_alpha(ALPHA * decal.0.fraction_remaining());
+        if decal.0.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Color of the entity at the time of death, using its animated hue if it has one.
+fn death_color(sprite: Option<&Sprite>, hue: Option<&HueAnimationState>) -> Color {
+    let mut color = sprite.map(|s| s.color).unwrap_or(Color::WHITE);
+    if let Some(hue) = hue {
+        color.set_hue(hue.0);
+    }
+    color
+}
+
+fn death_decal(sprite: &Sprite, transform: Transform, duration: Duration) -> impl Bundle {
+    let mut sprite = sprite.clone();
+    sprite.color = sprite.color.darker(0.3);
+
+    (
+        Name::new("DeathDecal"),
+        DeathDecal(Timer::new(duration, TimerMode::Once)),
+        sprite,
+        transform.with_translation(transform.translation - Vec3::Z * 0.5),
+        DespawnOnExit(Screen::Gameplay),
+    )
+}
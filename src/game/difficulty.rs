@@ -0,0 +1,248 @@
+//! Difficulty presets chosen before a run, with an optional adaptive mode
+//! adjusting wave sizes to how well the player is doing.
+
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        enemy::Enemy,
+        health::{DamageDealt, EntityDied, Health},
+        player::Player,
+    },
+    game_state::GameOver,
+    screens::Screen,
+};
+use avian2d::math::Scalar;
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunDifficulty>()
+        .init_resource::<AdaptiveDifficulty>();
+
+    app.add_observer(track_damage_taken)
+        .add_observer(track_kills);
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_adaptive_difficulty);
+
+    app.add_systems(
+        Update,
+        adjust_adaptive_difficulty
+            .run_if(in_state(GameOver(false)).and(is_adaptive))
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+    app.add_systems(
+        Update,
+        apply_player_difficulty
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// How often the adaptive mode re-evaluates the player's performance.
+const ADJUST_INTERVAL: Duration = Duration::from_secs(20);
+/// How much the wave size changes per adjustment.
+const ADJUST_STEP: f32 = 0.1;
+const MIN_ADAPTIVE_WAVE_SIZE: f32 = 0.6;
+const MAX_ADAPTIVE_WAVE_SIZE: f32 = 1.5;
+/// Taking more damage than this per interval makes waves smaller.
+const STRUGGLING_DAMAGE: u32 = 20;
+/// Taking less damage than this per interval, while killing at least
+/// [`DOMINATING_KILLS`] enemies, makes waves bigger.
+const DOMINATING_DAMAGE: u32 = 5;
+const DOMINATING_KILLS: u32 = 10;
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DifficultyPreset {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl DifficultyPreset {
+    pub const ALL: [Self; 3] = [Self::Easy, Self::Normal, Self::Hard];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+        }
+    }
+
+    pub fn settings(&self) -> DifficultySettings {
+        match self {
+            Self::Easy => DifficultySettings {
+                enemy_speed:   0.8,
+                enemy_health:  1.0,
+                wave_size:     0.75,
+                player_health: 1.5,
+            },
+            Self::Normal => DifficultySettings::default(),
+            Self::Hard => DifficultySettings {
+                enemy_speed:   1.2,
+                enemy_health:  1.5,
+                wave_size:     1.3,
+                player_health: 0.7,
+            },
+        }
+    }
+
+    /// The next harder preset, wrapping around.
+    pub fn next(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|preset| preset == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The next easier preset, wrapping around.
+    pub fn prev(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|preset| preset == self)
+            .unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Multipliers applied by a [`DifficultyPreset`].
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct DifficultySettings {
+    pub enemy_speed:   Scalar,
+    pub enemy_health:  f32,
+    /// Multiplier for the amount of enemies per wave
+    pub wave_size:     f32,
+    pub player_health: f32,
+}
+
+impl Default for DifficultySettings {
+    fn default() -> Self {
+        Self {
+            enemy_speed:   1.0,
+            enemy_health:  1.0,
+            wave_size:     1.0,
+            player_health: 1.0,
+        }
+    }
+}
+
+impl std::ops::Mul for DifficultySettings {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            enemy_speed:   self.enemy_speed * rhs.enemy_speed,
+            enemy_health:  self.enemy_health * rhs.enemy_health,
+            wave_size:     self.wave_size * rhs.wave_size,
+            player_health: self.player_health * rhs.player_health,
+        }
+    }
+}
+
+/// Difficulty the player starts a run with.
+#[derive(Resource, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Resource)]
+pub struct RunDifficulty {
+    pub preset:    DifficultyPreset,
+    /// Adjust wave sizes to the player's performance during the run
+    pub adaptive:  bool,
+    /// Extra multipliers on top of the preset, from the daily challenge
+    pub modifiers: DifficultySettings,
+}
+
+impl RunDifficulty {
+    pub fn settings(&self) -> DifficultySettings {
+        self.preset.settings() * self.modifiers
+    }
+
+    /// Wave size multiplier of the preset, combined with the adaptive adjustment if enabled.
+    pub fn wave_size(&self, adaptive: &AdaptiveDifficulty) -> f32 {
+        let wave_size = self.settings().wave_size;
+        if self.adaptive {
+            wave_size * adaptive.wave_size
+        } else {
+            wave_size
+        }
+    }
+}
+
+/// Tracks the player's performance during the current adjustment interval.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct AdaptiveDifficulty {
+    /// Current wave size multiplier, within bounds
+    pub wave_size: f32,
+    damage_taken:  u32,
+    kills:         u32,
+    timer:         Timer,
+}
+
+impl Default for AdaptiveDifficulty {
+    fn default() -> Self {
+        Self {
+            wave_size:    1.0,
+            damage_taken: 0,
+            kills:        0,
+            timer:        Timer::new(ADJUST_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+fn is_adaptive(difficulty: Res<RunDifficulty>) -> bool {
+    difficulty.adaptive
+}
+
+fn reset_adaptive_difficulty(mut adaptive: ResMut<AdaptiveDifficulty>) {
+    *adaptive = AdaptiveDifficulty::default();
+}
+
+fn track_damage_taken(
+    event: On<DamageDealt>,
+    mut adaptive: ResMut<AdaptiveDifficulty>,
+    players: Query<(), With<Player>>,
+) {
+    if players.contains(event.target) {
+        adaptive.damage_taken += event.amount;
+    }
+}
+
+fn track_kills(
+    event: On<EntityDied>,
+    mut adaptive: ResMut<AdaptiveDifficulty>,
+    enemies: Query<(), With<Enemy>>,
+) {
+    if enemies.contains(event.entity) {
+        adaptive.kills += 1;
+    }
+}
+
+fn adjust_adaptive_difficulty(time: Res<Time>, mut adaptive: ResMut<AdaptiveDifficulty>) {
+    adaptive.timer.tick(time.delta());
+    if !adaptive.timer.just_finished() {
+        return;
+    }
+
+    if adaptive.damage_taken > STRUGGLING_DAMAGE {
+        adaptive.wave_size -= ADJUST_STEP;
+    } else if adaptive.damage_taken < DOMINATING_DAMAGE && adaptive.kills >= DOMINATING_KILLS {
+        adaptive.wave_size += ADJUST_STEP;
+    }
+    adaptive.wave_size = adaptive
+        .wave_size
+        .clamp(MIN_ADAPTIVE_WAVE_SIZE, MAX_ADAPTIVE_WAVE_SIZE);
+
+    adaptive.damage_taken = 0;
+    adaptive.kills = 0;
+}
+
+fn apply_player_difficulty(
+    difficulty: Res<RunDifficulty>,
+    players: Query<&mut Health, Added<Player>>,
+) {
+    for mut health in players {
+        *health = health.scaled(difficulty.settings().player_health);
+    }
+}
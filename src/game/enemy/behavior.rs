@@ -0,0 +1,279 @@
+//! Movement behaviors of enemies, picked randomly per [`EnemyVariant`].
+
+use super::{
+    EnemiesEnabled, Enemy, EnemyGoal, EnemySettings, EnemyStunned, EnemyVariant,
+    elite::{RALLY_SPEED_MULTIPLIER, Rallied},
+    flocking::{EnemySpatialHash, FlockingSettings, rebuild_spatial_hash},
+};
+use crate::{
+    AppSystems, GameplaySet,
+    game::{bullet::Bullet, health::Dead, run_rng::RunRng},
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use rand::Rng;
+use std::{f32::consts::TAU, time::Duration};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (rebuild_spatial_hash, run_enemy_behavior)
+            .chain()
+            .run_if(in_state(EnemiesEnabled(true)))
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Bullets faster than this are considered dangerous by fleeing enemies.
+const DANGEROUS_BULLET_SPEED: Scalar = 50.0;
+
+/// How an enemy moves towards its goal.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub enum EnemyBehavior {
+    /// Move straight towards the goal
+    Seek,
+    /// Approach the goal from the side, sweeping in as it gets closer
+    Flank {
+        /// `1.0` or `-1.0`, which side to flank from
+        side: f32,
+    },
+    /// Circle around the goal, slowly spiraling in
+    Orbit { radius: Scalar, clockwise: bool },
+    /// Weave left and right while approaching
+    ZigZag { frequency: f32, amplitude: f32 },
+    /// Wander around aimlessly, then rush towards the goal for a moment
+    WanderThenCharge {
+        timer:    Timer,
+        charging: bool,
+        wander:   Vec2,
+    },
+    /// Seek, but run away from flying bullets nearby
+    Skittish { flee_radius: Scalar },
+}
+
+impl EnemyBehavior {
+    const CHARGE_SPEED: Scalar = 2.5;
+    const WANDER_SPEED: Scalar = 0.4;
+    const WANDER_DURATION: Duration = Duration::from_secs(3);
+    const CHARGE_DURATION: Duration = Duration::from_millis(800);
+
+    /// Pick a random behavior fitting the given variant.
+    pub fn random_for(variant: EnemyVariant, rng: &mut impl Rng) -> Self {
+        let side = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
+
+        match variant {
+            EnemyVariant::Basic => match rng.random_range(0 .. 10) {
+                0 ..= 2 => Self::Seek,
+                3 ..= 4 => Self::Flank { side },
+                5 => Self::Orbit {
+                    radius:    rng.random_range(60.0 .. 100.0),
+                    clockwise: side > 0.0,
+                },
+                6 ..= 7 => Self::ZigZag {
+                    frequency: rng.random_range(1.5 .. 3.0),
+                    amplitude: rng.random_range(0.6 .. 1.0),
+                },
+                _ => Self::Skittish {
+                    flee_radius: rng.random_range(40.0 .. 70.0),
+                },
+            },
+            EnemyVariant::Bigger => match rng.random_range(0 .. 4) {
+                0 => Self::Seek,
+                1 => Self::Flank { side },
+                _ => Self::wander_then_charge(rng.random_range(0.0 .. TAU)),
+            },
+            // Bosses have their own attack patterns on top of seeking
+            EnemyVariant::Boss => Self::Seek,
+        }
+    }
+
+    fn wander_then_charge(angle: f32) -> Self {
+        Self::WanderThenCharge {
+            timer:    Timer::new(Self::WANDER_DURATION, TimerMode::Once),
+            charging: false,
+            wander:   Vec2::from_angle(angle),
+        }
+    }
+}
+
+/// Direction the enemy last sought its goal in, kept while stunned or knocked back.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component)]
+pub struct Facing(pub Vec2);
+
+/// Randomized wobble added on top of an enemy's behavior, so groups don't move in lockstep.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct MovementNoise {
+    /// Max deflection in radians
+    pub strength:  f32,
+    pub frequency: f32,
+    pub seed:      f32,
+}
+
+impl Default for MovementNoise {
+    fn default() -> Self {
+        Self::random(&mut rand::rng())
+    }
+}
+
+impl MovementNoise {
+    pub fn random(rng: &mut impl Rng) -> Self {
+        Self {
+            strength:  rng.random_range(0.2 .. 0.5),
+            frequency: rng.random_range(0.5 .. 1.5),
+            seed:      rng.random_range(0.0 .. 100.0),
+        }
+    }
+}
+
+impl MovementNoise {
+    /// Cheap smooth noise from layered sines, in `-1.0 ..= 1.0`.
+    fn sample(&self, time: f32) -> f32 {
+        let t = time * self.frequency + self.seed;
+        (t.sin() + (t * 2.3 + 1.7).sin() * 0.5) / 1.5
+    }
+}
+
+fn run_enemy_behavior(
+    time: Res<Time>,
+    mut rng: ResMut<RunRng>,
+    flocking: Res<FlockingSettings>,
+    spatial_hash: Res<EnemySpatialHash>,
+    enemies: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &EnemySettings,
+            &mut EnemyBehavior,
+            Option<&MovementNoise>,
+            Has<Rallied>,
+            &mut Facing,
+            &mut LinearVelocity,
+        ),
+        (
+            With<Enemy>,
+            Without<EnemyStunned>,
+            Without<Dead>,
+            Without<EnemyGoal>,
+        ),
+    >,
+    goals: Query<&GlobalTransform, (With<EnemyGoal>, Without<Enemy>)>,
+    bullets: Query<(&GlobalTransform, &LinearVelocity), (With<Bullet>, Without<Enemy>)>,
+) {
+    let delta = time.delta_secs();
+    let elapsed = time.elapsed_secs();
+
+    for (entity, transform, settings, mut behavior, noise, is_rallied, mut facing, mut velocity) in
+        enemies
+    {
+        let position = transform.translation().truncate();
+
+        let Some(target) = goals
+            .iter()
+            .map(|goal| goal.translation().truncate())
+            .min_by(|a, b| {
+                a.distance_squared(position)
+                    .total_cmp(&b.distance_squared(position))
+            })
+        else {
+            continue;
+        };
+
+        let to_target = target - position;
+        let distance = to_target.length();
+        let seek = to_target.normalize_or_zero();
+        if seek != Vec2::ZERO {
+            facing.0 = seek;
+        }
+
+        let (direction, speed) = match &mut *behavior {
+            EnemyBehavior::Seek => (seek, 1.0),
+            EnemyBehavior::Flank { side } => {
+                // Sweep in from the side while far away, go straight for it when close
+                let sweep = (distance / 150.0).clamp(0.0, 1.0);
+                (
+                    (seek + seek.perp() * *side * sweep).normalize_or_zero(),
+                    1.0,
+                )
+            },
+            EnemyBehavior::Orbit { radius, clockwise } => {
+                let tangent = if *clockwise {
+                    -seek.perp()
+                } else {
+                    seek.perp()
+                };
+                let pull = ((distance - *radius) / *radius).clamp(-1.0, 1.0);
+                // Slowly spiral inwards
+                *radius = (*radius - 5.0 * delta).max(0.0);
+                ((tangent + seek * pull).normalize_or_zero(), 1.0)
+            },
+            EnemyBehavior::ZigZag {
+                frequency,
+                amplitude,
+            } => {
+                let weave = (elapsed * *frequency * TAU).sin() * *amplitude;
+                ((seek + seek.perp() * weave).normalize_or_zero(), 1.0)
+            },
+            EnemyBehavior::WanderThenCharge {
+                timer,
+                charging,
+                wander,
+            } => {
+                timer.tick(time.delta());
+                if timer.is_finished() {
+                    *charging = !*charging;
+                    *timer = Timer::new(
+                        if *charging {
+                            EnemyBehavior::CHARGE_DURATION
+                        } else {
+                            EnemyBehavior::WANDER_DURATION
+                        },
+                        TimerMode::Once,
+                    );
+                    *wander = Vec2::from_angle(rng.ai.random_range(0.0 .. TAU));
+                }
+
+                if *charging {
+                    (seek, EnemyBehavior::CHARGE_SPEED)
+                } else {
+                    (*wander, EnemyBehavior::WANDER_SPEED)
+                }
+            },
+            EnemyBehavior::Skittish { flee_radius } => {
+                let threat = bullets
+                    .iter()
+                    .filter(|(_, bullet_velocity)| {
+                        bullet_velocity.length() > DANGEROUS_BULLET_SPEED
+                    })
+                    .map(|(bullet, _)| bullet.translation().truncate())
+                    .find(|bullet| bullet.distance(position) < *flee_radius);
+
+                match threat {
+                    Some(bullet) => ((position - bullet).normalize_or_zero(), 1.5),
+                    None => (seek, 1.0),
+                }
+            },
+        };
+
+        let direction = match noise {
+            Some(noise) => {
+                Vec2::from_angle(noise.sample(elapsed) * noise.strength).rotate(direction)
+            },
+            None => direction,
+        };
+
+        let flock = spatial_hash.steering(&flocking, entity, position, velocity.0);
+        let direction = (direction + flock).clamp_length_max(1.0 + flocking.separation);
+
+        let speed = if is_rallied {
+            speed * RALLY_SPEED_MULTIPLIER
+        } else {
+            speed
+        };
+
+        velocity.0 += direction * settings.speed * speed * delta;
+    }
+}
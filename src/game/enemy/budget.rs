@@ -0,0 +1,261 @@
+//! Keeps the amount of live enemies in check: caps concurrent enemies and queues the overflow,
+//! merges far away stragglers into bigger enemies and culls enemies that left the map.
+
+use super::{Elite, EnemiesEnabled, Enemy, EnemyGoal, EnemyVariant};
+use crate::{
+    AppSystems, GameplaySet,
+    game::{health::Dead, solid::Solid},
+    screens::Screen,
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+use std::{collections::VecDeque, time::Duration};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<EnemyBudget>()
+        .init_resource::<EnemySpawnQueue>()
+        .init_resource::<EnemyCount>()
+        .init_resource::<MergeTimer>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), clear_spawn_queue);
+
+    app.add_systems(PreUpdate, count_live_enemies.in_set(GameplaySet));
+
+    app.add_systems(
+        Update,
+        (spawn_queued_enemies, merge_stragglers, cull_escaped_enemies)
+            .run_if(in_state(EnemiesEnabled(true)))
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct EnemyBudget {
+    /// Max concurrent live enemies, further spawns are queued
+    pub max_enemies:    usize,
+    /// Basic enemies further than this from their goal are considered stragglers
+    pub merge_distance: Scalar,
+    /// Amount of stragglers merged into one bigger enemy
+    pub merge_count:    usize,
+    /// Only stragglers within this distance of each other are merged
+    pub merge_radius:   Scalar,
+    pub merge_interval: Duration,
+    /// Enemies further than this outside the map bounds are despawned
+    pub cull_margin:    Scalar,
+}
+
+impl Default for EnemyBudget {
+    fn default() -> Self {
+        Self {
+            max_enemies:    150,
+            merge_distance: 350.0,
+            merge_count:    5,
+            merge_radius:   80.0,
+            merge_interval: Duration::from_secs(2),
+            cull_margin:    150.0,
+        }
+    }
+}
+
+impl EnemyBudget {
+    /// How many more enemies may be spawned with `live` enemies alive.
+    pub fn available(&self, live: usize) -> usize {
+        self.max_enemies.saturating_sub(live)
+    }
+}
+
+/// An enemy that couldn't be spawned because the [`EnemyBudget`] was exhausted.
+#[derive(Reflect, Clone, Debug)]
+pub struct QueuedEnemy {
+    pub variant:  EnemyVariant,
+    pub position: Vec2,
+    pub elite:    Option<Elite>,
+}
+
+/// Enemies waiting for room in the [`EnemyBudget`], spawned oldest first.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct EnemySpawnQueue(VecDeque<QueuedEnemy>);
+
+impl EnemySpawnQueue {
+    pub fn push(&mut self, enemy: QueuedEnemy) {
+        self.0.push_back(enemy);
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Live enemies counted once per frame before anything spawns, plus every enemy spawned since.
+/// All spawning systems reserve room through this, so their spawns share the same
+/// [`EnemyBudget`] even before their commands are applied.
+#[derive(Resource, Default, Debug)]
+pub struct EnemyCount(usize);
+
+impl EnemyCount {
+    /// Count one more enemy if the budget has room for it.
+    pub fn try_reserve(&mut self, budget: &EnemyBudget) -> bool {
+        if budget.available(self.0) == 0 {
+            return false;
+        }
+        self.0 += 1;
+        true
+    }
+}
+
+#[derive(Resource, Default)]
+struct MergeTimer(Timer);
+
+fn clear_spawn_queue(mut queue: ResMut<EnemySpawnQueue>, mut merge_timer: ResMut<MergeTimer>) {
+    queue.0.clear();
+    merge_timer.0.reset();
+}
+
+fn count_live_enemies(
+    mut count: ResMut<EnemyCount>,
+    enemies: Query<(), (With<Enemy>, Without<Dead>)>,
+) {
+    count.0 = enemies.iter().count();
+}
+
+fn spawn_queued_enemies(
+    mut commands: Commands,
+    budget: Res<EnemyBudget>,
+    mut count: ResMut<EnemyCount>,
+    mut queue: ResMut<EnemySpawnQueue>,
+) {
+    while !queue.is_empty() && count.try_reserve(&budget) {
+        let Some(queued) = queue.0.pop_front() else {
+            break;
+        };
+        let mut enemy = commands.spawn((
+            Enemy,
+            queued.variant,
+            Name::new("Queued Enemy"),
+            Transform::from_translation(queued.position.extend(0.0)),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+        if let Some(elite) = queued.elite {
+            enemy.insert(elite);
+        }
+    }
+}
+
+/// Merge basic enemies lagging far behind into bigger enemies, so they stop counting
+/// towards the budget while still catching up eventually.
+fn merge_stragglers(
+    mut commands: Commands,
+    time: Res<Time>,
+    budget: Res<EnemyBudget>,
+    mut merge_timer: ResMut<MergeTimer>,
+    enemies: Query<
+        (Entity, &GlobalTransform, &EnemyVariant),
+        (With<Enemy>, Without<Elite>, Without<Dead>),
+    >,
+    goals: Query<&GlobalTransform, With<EnemyGoal>>,
+) {
+    if merge_timer.0.duration() != budget.merge_interval {
+        merge_timer.0 = Timer::new(budget.merge_interval, TimerMode::Repeating);
+    }
+    merge_timer.0.tick(time.delta());
+    if !merge_timer.0.just_finished() || budget.merge_count < 2 {
+        return;
+    }
+
+    let goals = goals
+        .iter()
+        .map(|goal| goal.translation().truncate())
+        .collect::<Vec<_>>();
+    if goals.is_empty() {
+        return;
+    }
+
+    let mut stragglers = enemies
+        .iter()
+        .filter(|(_, _, variant)| **variant == EnemyVariant::Basic)
+        .map(|(entity, transform, _)| (entity, transform.translation().truncate()))
+        .filter(|(_, position)| {
+            goals
+                .iter()
+                .all(|goal| goal.distance(*position) > budget.merge_distance)
+        })
+        .collect::<Vec<_>>();
+
+    // Group each straggler with its nearest neighbors, so far apart enemies are never fused
+    while let Some((seed_entity, seed)) = stragglers.pop() {
+        let mut neighbors = stragglers
+            .iter()
+            .enumerate()
+            .map(|(index, (_, position))| (index, position.distance(seed)))
+            .filter(|(_, distance)| *distance <= budget.merge_radius)
+            .collect::<Vec<_>>();
+        if neighbors.len() + 1 < budget.merge_count {
+            continue;
+        }
+        neighbors.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        let mut indices = neighbors
+            .into_iter()
+            .take(budget.merge_count - 1)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+        let group = indices
+            .into_iter()
+            .map(|index| stragglers.swap_remove(index))
+            .collect::<Vec<_>>();
+
+        let center = (group.iter().map(|(_, position)| *position).sum::<Vec2>() + seed)
+            / budget.merge_count as f32;
+        if goals
+            .iter()
+            .any(|goal| goal.distance(center) <= budget.merge_distance)
+        {
+            continue;
+        }
+        commands.entity(seed_entity).despawn();
+        for (entity, _) in group {
+            commands.entity(entity).despawn();
+        }
+        commands.spawn((
+            Enemy,
+            EnemyVariant::Bigger,
+            Name::new("Merged Enemy"),
+            Transform::from_translation(center.extend(0.0)),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+/// Despawn enemies that got pushed far outside the map's solid bounds.
+fn cull_escaped_enemies(
+    mut commands: Commands,
+    budget: Res<EnemyBudget>,
+    solids: Query<&ColliderAabb, With<Solid>>,
+    enemies: Query<(Entity, &GlobalTransform, &EnemyVariant), (With<Enemy>, Without<Dead>)>,
+) {
+    let Some(bounds) = solids
+        .iter()
+        .map(|aabb| Rect::from_corners(aabb.min, aabb.max))
+        .reduce(|bounds, rect| bounds.union(rect))
+    else {
+        return;
+    };
+    let bounds = bounds.inflate(budget.cull_margin);
+
+    for (entity, transform, variant) in enemies {
+        // Bosses end their fight on death, never cull them
+        if *variant == EnemyVariant::Boss {
+            continue;
+        }
+        if !bounds.contains(transform.translation().truncate()) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
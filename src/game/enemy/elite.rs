@@ -0,0 +1,349 @@
+//! Elite enemies with a random affix, becoming more common in later waves.
+
+use super::{Enemy, EnemySettings, EnemyVariant};
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        dash::Dashing,
+        health::{DamageCause, DamageDealt, Dead, EntityDied, Health, deal_damage},
+        player::{Invincible, Player},
+        run_rng::RunRng,
+        visuals::{HueAnimation, SetSpriteColor},
+    },
+    screens::Screen,
+};
+use bevy::prelude::*;
+use rand::Rng;
+use std::{f32::consts::TAU, time::Duration};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(split_on_death);
+
+    app.add_systems(
+        Update,
+        (tick_rallied, tick_hazards, tick_hazard_immunity)
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            init_elites,
+            drop_hazard_trails,
+            damage_player_on_hazards,
+            rally_nearby_enemies,
+            draw_elite_outlines,
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Chance for an enemy to be elite per wave index, capped at [`MAX_ELITE_CHANCE`].
+const ELITE_CHANCE_PER_WAVE: f64 = 0.02;
+const MAX_ELITE_CHANCE: f64 = 0.4;
+const ELITE_SCORE_MULTIPLIER: u32 = 3;
+
+/// Hits coming from within this angle (in radians) of a shielded elite's facing are blocked.
+const SHIELD_ANGLE: f32 = 1.0;
+
+const SPLIT_COUNT: u32 = 2;
+const SPLIT_DISTANCE: f32 = 10.0;
+
+const HAZARD_INTERVAL: Duration = Duration::from_millis(300);
+const HAZARD_LIFETIME: Duration = Duration::from_secs(3);
+const HAZARD_RADIUS: f32 = 5.0;
+const HAZARD_DAMAGE: u32 = 1;
+const HAZARD_IMMUNITY: Duration = Duration::from_secs(1);
+
+const RALLY_RADIUS: f32 = 60.0;
+pub(super) const RALLY_SPEED_MULTIPLIER: f32 = 1.4;
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EliteAffix {
+    /// Blocks bullets hitting it from the front
+    Shielded,
+    /// Splits into smaller enemies on death
+    Splitting,
+    /// Leaves a trail of hazards hurting the player
+    HazardTrail,
+    /// Speeds up enemies around it
+    Rallying,
+}
+
+impl EliteAffix {
+    pub const ALL: [Self; 4] = [
+        Self::Shielded,
+        Self::Splitting,
+        Self::HazardTrail,
+        Self::Rallying,
+    ];
+
+    pub fn hue(&self) -> f32 {
+        match self {
+            Self::Shielded => 200.0,
+            Self::Splitting => 300.0,
+            Self::HazardTrail => 90.0,
+            Self::Rallying => 45.0,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        Color::hsl(self.hue(), 0.9, 0.6)
+    }
+}
+
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component)]
+pub struct Elite(pub EliteAffix);
+
+impl Elite {
+    /// Roll whether an enemy of the given wave becomes elite, with a random affix.
+    pub fn roll(wave_index: u32, rng: &mut impl Rng) -> Option<Self> {
+        let chance = (wave_index as f64 * ELITE_CHANCE_PER_WAVE).min(MAX_ELITE_CHANCE);
+        rng.random_bool(chance)
+            .then(|| Self(EliteAffix::ALL[rng.random_range(0 .. EliteAffix::ALL.len())]))
+    }
+
+    /// Whether a hit pushing the enemy in `direction` is blocked by its shield,
+    /// while the enemy is facing in `facing`.
+    pub fn blocks_hit(&self, facing: Vec2, direction: Vec2) -> bool {
+        if self.0 != EliteAffix::Shielded {
+            return false;
+        }
+        let Some(facing) = facing.try_normalize() else {
+            return false;
+        };
+        // Hits from the front push the enemy backwards
+        facing.angle_to(-direction).abs() < SHIELD_ANGLE
+    }
+}
+
+#[derive(Component)]
+struct HazardDropper(Timer);
+
+/// Damaging puddle left behind by a [`EliteAffix::HazardTrail`] elite.
+#[derive(Component)]
+#[require(Name::new("Hazard"), DespawnOnExit::<_>(Screen::Gameplay))]
+struct Hazard(Timer);
+
+/// Player can't be hurt by hazards until the timer finishes.
+#[derive(Component)]
+struct HazardImmunity(Timer);
+
+/// Sped up by a nearby [`EliteAffix::Rallying`] elite.
+#[derive(Component)]
+pub(super) struct Rallied(Timer);
+
+fn init_elites(
+    mut commands: Commands,
+    elites: Query<
+        (
+            Entity,
+            &Elite,
+            &mut EnemySettings,
+            Option<&mut HueAnimation>,
+        ),
+        Added<EnemySettings>,
+    >,
+) {
+    for (entity, elite, mut settings, hue) in elites {
+        settings.score_worth *= ELITE_SCORE_MULTIPLIER;
+
+        // Brighter than regular enemies, cycling around the affix' color
+        commands
+            .entity(entity)
+            .insert(SetSpriteColor(elite.0.color()));
+        if let Some(mut hue) = hue {
+            let affix_hue = elite.0.hue();
+            hue.0.range = Some((affix_hue - 20.0, affix_hue + 20.0));
+        }
+
+        if elite.0 == EliteAffix::HazardTrail {
+            commands.entity(entity).insert(HazardDropper(Timer::new(
+                HAZARD_INTERVAL,
+                TimerMode::Repeating,
+            )));
+        }
+    }
+}
+
+fn draw_elite_outlines(
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    elites: Query<(&GlobalTransform, &Elite), (With<Enemy>, Without<Dead>)>,
+) {
+    const OUTLINE_RADIUS: f32 = 11.0;
+
+    let pulse = (time.elapsed_secs() * 6.0).sin() * 0.5 + 0.5;
+    for (transform, elite) in elites {
+        let position = transform.translation().truncate();
+        let radius = OUTLINE_RADIUS * transform.scale().x + pulse;
+        gizmos.circle_2d(position, radius, elite.0.color());
+
+        if elite.0 == EliteAffix::Rallying {
+            gizmos.circle_2d(position, RALLY_RADIUS, elite.0.color().with_alpha(0.2));
+        }
+    }
+}
+
+fn split_on_death(
+    event: On<EntityDied>,
+    mut commands: Commands,
+    mut rng: ResMut<RunRng>,
+    elites: Query<(&GlobalTransform, &Elite), With<Enemy>>,
+) {
+    let Ok((transform, elite)) = elites.get(event.entity) else {
+        return;
+    };
+    if elite.0 != EliteAffix::Splitting {
+        return;
+    }
+
+    let position = transform.translation().truncate();
+    let offset_angle = rng.ai.random_range(0.0 .. TAU);
+    for index in 0 .. SPLIT_COUNT {
+        let angle = offset_angle + index as f32 / SPLIT_COUNT as f32 * TAU;
+        let offset = Vec2::from_angle(angle) * SPLIT_DISTANCE * transform.scale().x;
+        // Splits are always basic, non-elite enemies
+        commands.spawn((
+            Enemy,
+            EnemyVariant::Basic,
+            Name::new("Elite Split"),
+            Transform::from_translation((position + offset).extend(0.0)),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+fn drop_hazard_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    droppers: Query<(&GlobalTransform, &mut HazardDropper), Without<Dead>>,
+) {
+    for (transform, mut dropper) in droppers {
+        dropper.0.tick(time.delta());
+        if !dropper.0.just_finished() {
+            continue;
+        }
+
+        commands.spawn((
+            Hazard(Timer::new(HAZARD_LIFETIME, TimerMode::Once)),
+            Sprite::from_color(
+                EliteAffix::HazardTrail.color().with_alpha(0.6),
+                Vec2::splat(HAZARD_RADIUS * 2.0),
+            ),
+            Transform::from_translation(transform.translation().with_z(-0.4)),
+        ));
+    }
+}
+
+fn tick_hazards(
+    mut commands: Commands,
+    time: Res<Time>,
+    hazards: Query<(Entity, &mut Hazard, &mut Sprite)>,
+) {
+    for (entity, mut hazard, mut sprite) in hazards {
+        hazard.0.tick(time.delta());
+        if hazard.0.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        sprite.color.set_alpha(0.6 * hazard.0.fraction_remaining());
+    }
+}
+
+fn tick_hazard_immunity(
+    mut commands: Commands,
+    time: Res<Time>,
+    players: Query<(Entity, &mut HazardImmunity)>,
+) {
+    for (entity, mut immunity) in players {
+        immunity.0.tick(time.delta());
+        if immunity.0.is_finished() {
+            commands.entity(entity).remove::<HazardImmunity>();
+        }
+    }
+}
+
+fn damage_player_on_hazards(
+    mut commands: Commands,
+    hazards: Query<&GlobalTransform, With<Hazard>>,
+    players: Query<
+        (Entity, &GlobalTransform, Option<&mut Health>),
+        (
+            With<Player>,
+            Without<HazardImmunity>,
+            Without<Invincible>,
+            Without<Dashing>,
+            Without<Dead>,
+        ),
+    >,
+) {
+    const PLAYER_RADIUS: f32 = 8.0;
+
+    for (player, player_transform, health) in players {
+        let position = player_transform.translation().truncate();
+        let touching = hazards.iter().any(|hazard| {
+            hazard.translation().truncate().distance(position) < HAZARD_RADIUS + PLAYER_RADIUS
+        });
+        if !touching {
+            continue;
+        }
+
+        commands
+            .entity(player)
+            .insert(HazardImmunity(Timer::new(HAZARD_IMMUNITY, TimerMode::Once)));
+        if let Some(mut health) = health {
+            deal_damage(
+                &mut commands,
+                &mut health,
+                DamageDealt::new(player, HAZARD_DAMAGE, DamageCause::Hazard),
+            );
+        }
+    }
+}
+
+fn rally_nearby_enemies(
+    mut commands: Commands,
+    elites: Query<(Entity, &GlobalTransform, &Elite), (With<Enemy>, Without<Dead>)>,
+    mut enemies: Query<
+        (Entity, &GlobalTransform, Option<&mut Rallied>),
+        (With<Enemy>, Without<Dead>),
+    >,
+) {
+    const RALLY_DURATION: Duration = Duration::from_millis(250);
+
+    for (elite_entity, elite_transform, elite) in elites {
+        if elite.0 != EliteAffix::Rallying {
+            continue;
+        }
+
+        let center = elite_transform.translation().truncate();
+        for (entity, transform, rallied) in &mut enemies {
+            if entity == elite_entity
+                || transform.translation().truncate().distance(center) > RALLY_RADIUS
+            {
+                continue;
+            }
+            match rallied {
+                Some(mut rallied) => rallied.0.reset(),
+                None => {
+                    commands
+                        .entity(entity)
+                        .insert(Rallied(Timer::new(RALLY_DURATION, TimerMode::Once)));
+                },
+            }
+        }
+    }
+}
+
+fn tick_rallied(mut commands: Commands, time: Res<Time>, enemies: Query<(Entity, &mut Rallied)>) {
+    for (entity, mut rallied) in enemies {
+        rallied.0.tick(time.delta());
+        if rallied.0.is_finished() {
+            commands.entity(entity).remove::<Rallied>();
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Enemies get faster and angrier every time they are stunned by a bullet.
+
+use super::{Enemy, EnemySettings, EnemyStunned, EnemyVariant};
+use crate::{
+    AppSystems, GameplaySet,
+    game::{health::Dead, visuals::HueAnimation},
+};
+use avian2d::math::Scalar;
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        escalate_stunned_enemies
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+const MAX_LEVEL: u32 = 5;
+const SPEED_GAIN: Scalar = 1.15;
+const STUN_DURATION_FACTOR: f32 = 0.8;
+const MIN_STUN_DURATION: Duration = Duration::from_millis(300);
+const SCALE_GAIN: f32 = 1.08;
+/// Hue range the enemy's color cycles through at max escalation, reds only.
+const MAX_LEVEL_HUE_RANGE: (f32, f32) = (0.0, 30.0);
+
+/// How many times an enemy has been stunned, up to a max level.
+#[derive(Component, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Component)]
+pub struct EnemyEscalation(pub u32);
+
+fn escalate_stunned_enemies(
+    enemies: Query<
+        (
+            &EnemyVariant,
+            &mut EnemyEscalation,
+            &mut EnemySettings,
+            &mut Transform,
+            Option<&mut HueAnimation>,
+        ),
+        (With<Enemy>, Added<EnemyStunned>, Without<Dead>),
+    >,
+) {
+    for (variant, mut escalation, mut settings, mut transform, hue) in enemies {
+        // Bosses escalate through their own phases
+        if *variant == EnemyVariant::Boss || escalation.0 >= MAX_LEVEL {
+            continue;
+        }
+        escalation.0 += 1;
+
+        settings.speed *= SPEED_GAIN;
+        settings.stun_duration = settings
+            .stun_duration
+            .mul_f32(STUN_DURATION_FACTOR)
+            .max(MIN_STUN_DURATION);
+
+        transform.scale.x *= SCALE_GAIN;
+        transform.scale.y *= SCALE_GAIN;
+
+        // Narrow the hue cycle towards red and speed it up
+        if let Some(mut hue) = hue {
+            let anger = escalation.0 as f32 / MAX_LEVEL as f32;
+            let max_hue = 360.0 + (MAX_LEVEL_HUE_RANGE.1 - 360.0) * anger;
+            hue.0.range = Some((MAX_LEVEL_HUE_RANGE.0, max_hue));
+            hue.0.period *= 0.8;
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Boids-style flocking between enemies, with a spatial hash for neighbor lookups.
+
+use super::Enemy;
+use crate::game::health::Dead;
+use avian2d::{math::Scalar, prelude::*};
+use bevy::{platform::collections::HashMap, prelude::*};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<FlockingSettings>()
+        .init_resource::<EnemySpatialHash>();
+}
+
+/// Weights of the flocking forces combined with an enemy's behavior.
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct FlockingSettings {
+    /// Neighbors within this distance are considered part of the flock
+    pub radius:        Scalar,
+    /// Push away from neighbors that are too close
+    pub separation:    Scalar,
+    /// Steer towards the average heading of neighbors
+    pub alignment:     Scalar,
+    /// Steer towards the center of neighbors
+    pub cohesion:      Scalar,
+    /// Only consider this many neighbors per enemy
+    pub max_neighbors: usize,
+}
+
+impl Default for FlockingSettings {
+    fn default() -> Self {
+        Self {
+            radius:        28.0,
+            separation:    1.6,
+            alignment:     0.3,
+            cohesion:      0.15,
+            max_neighbors: 12,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FlockMember {
+    entity:   Entity,
+    position: Vec2,
+    velocity: Vec2,
+}
+
+/// Enemies bucketed into grid cells of the flocking radius, rebuilt every frame.
+#[derive(Resource, Default)]
+pub(super) struct EnemySpatialHash {
+    cell_size: Scalar,
+    cells:     HashMap<IVec2, Vec<FlockMember>>,
+}
+
+impl EnemySpatialHash {
+    fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// Members in the cell of `position` and all surrounding cells.
+    fn nearby(&self, position: Vec2) -> impl Iterator<Item = &FlockMember> {
+        let center = self.cell(position);
+        (-1 ..= 1)
+            .flat_map(move |x| (-1 ..= 1).map(move |y| center + IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+    }
+
+    /// Combined separation, alignment and cohesion steering for the given enemy.
+    pub(super) fn steering(
+        &self,
+        settings: &FlockingSettings,
+        entity: Entity,
+        position: Vec2,
+        velocity: Vec2,
+    ) -> Vec2 {
+        let radius_squared = settings.radius * settings.radius;
+
+        let mut separation = Vec2::ZERO;
+        let mut heading = Vec2::ZERO;
+        let mut center = Vec2::ZERO;
+        let mut count = 0;
+
+        for other in self.nearby(position) {
+            if other.entity == entity {
+                continue;
+            }
+
+            let offset = position - other.position;
+            let distance_squared = offset.length_squared();
+            if distance_squared > radius_squared {
+                continue;
+            }
+
+            // Closer neighbors push harder
+            if distance_squared > f32::EPSILON {
+                separation += offset / distance_squared * settings.radius;
+            } else {
+                separation += Vec2::from_angle(entity.index() as f32);
+            }
+            heading += other.velocity;
+            center += other.position;
+
+            count += 1;
+            if count >= settings.max_neighbors {
+                break;
+            }
+        }
+
+        if count == 0 {
+            return Vec2::ZERO;
+        }
+
+        let count = count as f32;
+        let alignment = (heading / count - velocity).normalize_or_zero();
+        let cohesion = (center / count - position).normalize_or_zero();
+
+        separation * settings.separation
+            + alignment * settings.alignment
+            + cohesion * settings.cohesion
+    }
+}
+
+pub(super) fn rebuild_spatial_hash(
+    settings: Res<FlockingSettings>,
+    mut hash: ResMut<EnemySpatialHash>,
+    enemies: Query<(Entity, &GlobalTransform, &LinearVelocity), (With<Enemy>, Without<Dead>)>,
+) {
+    hash.cell_size = settings.radius.max(1.0);
+    for members in hash.cells.values_mut() {
+        members.clear();
+    }
+
+    for (entity, transform, velocity) in enemies {
+        let position = transform.translation().truncate();
+        let cell = hash.cell(position);
+        hash.cells.entry(cell).or_default().push(FlockMember {
+            entity,
+            position,
+            velocity: velocity.0,
+        });
+    }
+
+    hash.cells.retain(|_, members| !members.is_empty());
+}
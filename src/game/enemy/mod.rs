@@ -1,8 +1,12 @@
 use crate::{
     AppSystems, GameplaySet,
     asset_tracking::LoadResource,
+    audio::sound_effect,
     game::{
-        health::{Dead, Health},
+        death::DeathBehavior,
+        difficulty::{DifficultySettings, RunDifficulty},
+        health::{Dead, EntityDied, Health},
+        run_rng::RunRng,
         util::{CollisionTag, SetScale},
         visuals::{AnimationDirection, HueAnimation, SetSpriteColor, VisualAnimation},
     },
@@ -14,18 +18,34 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+mod behavior;
+mod budget;
+mod elite;
+mod escalation;
+mod flocking;
+
+pub use behavior::{EnemyBehavior, Facing, MovementNoise};
+pub use budget::{EnemyBudget, EnemyCount, EnemySpawnQueue, QueuedEnemy};
+pub use elite::{Elite, EliteAffix};
+pub use escalation::EnemyEscalation;
+
 pub fn plugin(app: &mut App) {
     app.load_resource::<EnemyAssets>();
     app.init_state::<EnemiesEnabled>();
 
+    app.add_plugins((
+        behavior::plugin,
+        budget::plugin,
+        elite::plugin,
+        escalation::plugin,
+        flocking::plugin,
+    ));
+
+    app.add_observer(play_death_sound);
+
     app.add_systems(
         Update,
-        (
-            post_add_enemy,
-            handle_variant_change,
-            run_enemy_behavior.run_if(in_state(EnemiesEnabled(true))),
-            handle_enemy_stun,
-        )
+        (post_add_enemy, handle_variant_change, handle_enemy_stun)
             .in_set(AppSystems::Update)
             .in_set(GameplaySet),
     );
@@ -48,6 +68,8 @@ fn post_add_enemy(
 
 fn handle_variant_change(
     mut commands: Commands,
+    difficulty: Res<RunDifficulty>,
+    mut rng: ResMut<RunRng>,
     enemies: Query<
         (Entity, &EnemyVariant, Option<&mut EnemyInitializedVariant>),
         (Changed<EnemyVariant>, With<Enemy>),
@@ -63,7 +85,8 @@ fn handle_variant_change(
         }
         commands.entity(entity).insert((
             EnemyInitializedVariant(*variant),
-            EnemyVariantBundle::from(*variant),
+            EnemyVariantBundle::new(*variant, &mut rng.ai).with_difficulty(difficulty.settings()),
+            MovementNoise::random(&mut rng.ai),
         ));
     }
 }
@@ -99,8 +122,19 @@ impl Default for EnemiesEnabled {
     ),
     LockedAxes::ROTATION_LOCKED,
     LinearDamping(10.0),
+    EnemyBehavior::Seek,
+    Facing,
+    MovementNoise,
+    EnemyEscalation,
 
     Health::new(1),
+    DeathBehavior {
+        animation: Some("death".into()),
+        shrink:    true,
+        duration:  Duration::from_millis(600),
+        particles: 12,
+        decal:     Some(Duration::from_secs(5)),
+    },
 
     SetSpriteColor(Color::hsl(0.0, 0.6, 0.8)),
     HueAnimation(VisualAnimation {
@@ -114,12 +148,26 @@ impl Default for EnemiesEnabled {
 pub struct Enemy;
 
 #[derive(
-    Component, Reflect, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default,
+    Component, Reflect, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Default,
 )]
 pub enum EnemyVariant {
     #[default]
     Basic,
     Bigger,
+    /// Spawned at boss wave milestones, see [`crate::game::boss`]
+    Boss,
+}
+
+impl EnemyVariant {
+    pub const ALL: [Self; 3] = [Self::Basic, Self::Bigger, Self::Boss];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Basic => "Basic",
+            Self::Bigger => "Bigger",
+            Self::Boss => "Boss",
+        }
+    }
 }
 
 #[derive(Bundle)]
@@ -127,10 +175,11 @@ struct EnemyVariantBundle {
     settings: EnemySettings,
     scale:    SetScale,
     health:   Health,
+    behavior: EnemyBehavior,
 }
 
-impl From<EnemyVariant> for EnemyVariantBundle {
-    fn from(variant: EnemyVariant) -> Self {
+impl EnemyVariantBundle {
+    fn new(variant: EnemyVariant, rng: &mut impl Rng) -> Self {
         match variant {
             EnemyVariant::Basic => Self {
                 settings: EnemySettings {
@@ -142,6 +191,7 @@ impl From<EnemyVariant> for EnemyVariantBundle {
                 },
                 scale:    Vec2::splat(1.0).into(),
                 health:   Health::new(1),
+                behavior: EnemyBehavior::random_for(variant, rng),
             },
             EnemyVariant::Bigger => Self {
                 settings: EnemySettings {
@@ -153,9 +203,28 @@ impl From<EnemyVariant> for EnemyVariantBundle {
                 },
                 scale:    Vec2::splat(2.0).into(),
                 health:   Health::new(3),
+                behavior: EnemyBehavior::random_for(variant, rng),
+            },
+            EnemyVariant::Boss => Self {
+                settings: EnemySettings {
+                    speed:                     180.0,
+                    stun_duration:             Duration::from_millis(400),
+                    knockback_strength:        1200.0,
+                    knockback_strength_bullet: 100.0,
+                    score_worth:               5000,
+                },
+                scale:    Vec2::splat(4.0).into(),
+                health:   Health::new(40),
+                behavior: EnemyBehavior::random_for(variant, rng),
             },
         }
     }
+
+    fn with_difficulty(mut self, difficulty: DifficultySettings) -> Self {
+        self.settings.speed *= difficulty.enemy_speed;
+        self.health = self.health.scaled(difficulty.enemy_health);
+        self
+    }
 }
 
 #[derive(Component, Reflect, Clone, Debug)]
@@ -195,6 +264,8 @@ struct EnemyInitializedVariant(EnemyVariant);
 struct EnemyAssets {
     #[dependency]
     spritesheet: Handle<Aseprite>,
+    #[dependency]
+    sfx_death:   Handle<AudioSource>,
 }
 
 impl FromWorld for EnemyAssets {
@@ -203,48 +274,21 @@ impl FromWorld for EnemyAssets {
             spritesheet: world
                 .resource::<AssetServer>()
                 .load("spritesheets/enemy.ase"),
+            sfx_death:   world
+                .resource::<AssetServer>()
+                .load("audio/sfx/explosion.ogg"),
         }
     }
 }
 
-fn run_enemy_behavior(
-    time: Res<Time>,
-    enemies: Query<
-        (&GlobalTransform, &EnemySettings, &mut LinearVelocity),
-        (
-            With<Enemy>,
-            Without<EnemyStunned>,
-            Without<Dead>,
-            Without<EnemyGoal>,
-        ),
-    >,
-    goals: Query<&GlobalTransform, (With<EnemyGoal>, Without<Enemy>)>,
+fn play_death_sound(
+    event: On<EntityDied>,
+    mut commands: Commands,
+    assets: Res<EnemyAssets>,
+    enemies: Query<(), With<Enemy>>,
 ) {
-    let delta = time.delta_secs();
-    for (transform, settings, mut velocity) in enemies {
-        let translation = transform.translation();
-
-        if let Some(target) = goals
-            .into_iter()
-            .fold(None, |acc: Option<(f32, Vec2)>, goal| {
-                let goal_translation = goal.translation();
-                let distance = translation.distance_squared(goal_translation);
-                Some(match acc {
-                    Some(nearest) => {
-                        if distance < nearest.0 {
-                            (distance, goal_translation.truncate())
-                        } else {
-                            nearest
-                        }
-                    },
-                    None => (distance, goal_translation.truncate()),
-                })
-            })
-            .map(|(_, goal)| goal)
-        {
-            let direction = (target - translation.truncate()).normalize();
-            velocity.0 += direction * settings.speed * delta;
-        }
+    if enemies.contains(event.entity) {
+        commands.spawn(sound_effect(assets.sfx_death.clone()));
     }
 }
 
@@ -253,11 +297,16 @@ fn handle_enemy_stun(
     mut commands: Commands,
     newly_stunned_enemies: Query<
         (Entity, &EnemySettings, &mut AseAnimation),
-        (With<Enemy>, Added<EnemyStunned>, Without<EnemyStunnedTimer>),
+        (
+            With<Enemy>,
+            Added<EnemyStunned>,
+            Without<EnemyStunnedTimer>,
+            Without<Dead>,
+        ),
     >,
     stunned_enemies: Query<
         (Entity, &mut EnemyStunnedTimer, &mut AseAnimation),
-        (With<Enemy>, With<EnemyStunned>),
+        (With<Enemy>, With<EnemyStunned>, Without<Dead>),
     >,
 ) {
     for (enemy, settings, mut ase) in newly_stunned_enemies {
@@ -0,0 +1,332 @@
+//! Game modes with their own game-over criteria and high-score tables.
+
+#[cfg(not(target_family = "wasm"))]
+use crate::game::statistics::SAVE_DIR;
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        daily::is_daily_run,
+        enemy::Enemy,
+        health::EntityDied,
+        mutators::{Mutators, RunMutators},
+        score::Score,
+        survival_timer::{SurvivalTimer, format_time},
+    },
+    game_state::GameOver,
+    screens::Screen,
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunGameMode>()
+        .init_resource::<GameModeSettings>()
+        .init_resource::<Kills>()
+        .insert_resource(HighScores::load());
+
+    app.add_observer(count_kills);
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_kills);
+    // Daily runs aren't comparable to regular ones, they have their own leaderboard
+    app.add_systems(
+        OnEnter(GameOver(true)),
+        record_high_score.run_if(not(is_daily_run)),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            check_game_mode_over.run_if(in_state(GameOver(false))),
+            render_objective,
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Only the best results of each mode are kept.
+const HIGH_SCORES_PER_MODE: usize = 10;
+#[cfg(not(target_family = "wasm"))]
+const HIGH_SCORES_FILE: &str = "high_scores.txt";
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum GameMode {
+    /// Survive as long as possible
+    #[default]
+    Endless,
+    /// Get the highest score within a fixed time
+    TimedArcade,
+    /// Kill a target amount of enemies as fast as possible
+    KillTarget,
+}
+
+impl GameMode {
+    pub const ALL: [Self; 3] = [Self::Endless, Self::TimedArcade, Self::KillTarget];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Endless => "Endless",
+            Self::TimedArcade => "Arcade",
+            Self::KillTarget => "Kill Target",
+        }
+    }
+
+    /// The next mode, wrapping around.
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The previous mode, wrapping around.
+    pub fn prev(&self) -> Self {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Game mode the player starts a run with.
+#[derive(Resource, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Resource)]
+pub struct RunGameMode(pub GameMode);
+
+#[derive(Resource, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+pub struct GameModeSettings {
+    /// Length of a [`GameMode::TimedArcade`] run
+    pub arcade_duration: Duration,
+    /// Kills needed to finish a [`GameMode::KillTarget`] run
+    pub kill_target:     u32,
+}
+
+impl Default for GameModeSettings {
+    fn default() -> Self {
+        Self {
+            arcade_duration: Duration::from_secs(180),
+            kill_target:     100,
+        }
+    }
+}
+
+/// Enemies killed by the player during the current run.
+#[derive(Resource, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Resource)]
+pub struct Kills(pub u32);
+
+/// Result of a finished run, compared within its mode's table.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HighScore {
+    /// Endless and arcade runs rank by score
+    Score(u32),
+    /// Kill target runs rank by how fast the target was reached
+    Time(Duration),
+}
+
+impl HighScore {
+    fn is_better_than(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Score(a), Self::Score(b)) => a > b,
+            (Self::Time(a), Self::Time(b)) => a < b,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for HighScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Score(score) => write!(f, "{score}"),
+            Self::Time(time) => write!(f, "{}", format_time(*time)),
+        }
+    }
+}
+
+/// Best results per game mode and set of mutators, best first.
+/// Runs with different mutators aren't comparable, so each set has its own table.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct HighScores(HashMap<(GameMode, Mutators), Vec<HighScore>>);
+
+impl HighScores {
+    pub fn table(&self, mode: GameMode, mutators: Mutators) -> &[HighScore] {
+        self.0
+            .get(&(mode, mutators))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn best(&self, mode: GameMode, mutators: Mutators) -> Option<HighScore> {
+        self.table(mode, mutators).first().copied()
+    }
+
+    fn insert(&mut self, mode: GameMode, mutators: Mutators, high_score: HighScore) {
+        let table = self.0.entry((mode, mutators)).or_default();
+        let index = table
+            .iter()
+            .position(|other| high_score.is_better_than(other))
+            .unwrap_or(table.len());
+        table.insert(index, high_score);
+        table.truncate(HIGH_SCORES_PER_MODE);
+    }
+
+    /// One line per result: game mode, mutators, then `score <points>` or `time <millis>`.
+    fn to_lines(&self) -> String {
+        let mut lines = String::new();
+        for mode in GameMode::ALL {
+            let mut tables = self
+                .0
+                .iter()
+                .filter(|((table_mode, _), _)| *table_mode == mode)
+                .collect::<Vec<_>>();
+            // Keep the file stable between saves
+            tables.sort_by_key(|((_, mutators), _)| mutators.key());
+            for ((_, mutators), table) in tables {
+                for high_score in table {
+                    let value = match high_score {
+                        HighScore::Score(score) => format!("score {score}"),
+                        HighScore::Time(time) => format!("time {}", time.as_millis()),
+                    };
+                    lines.push_str(&format!("{mode:?} {} {value}\n", mutators.key()));
+                }
+            }
+        }
+        lines
+    }
+
+    fn from_lines(content: &str) -> Self {
+        let mut high_scores = Self::default();
+        for line in content.lines() {
+            let mut parts = line.split(' ');
+            let (Some(mode), Some(mutators), Some(kind), Some(value)) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+            let Some(mode) = GameMode::ALL
+                .into_iter()
+                .find(|other| format!("{other:?}") == mode)
+            else {
+                continue;
+            };
+            let (Some(mutators), Ok(value)) = (Mutators::from_key(mutators), value.parse::<u64>())
+            else {
+                continue;
+            };
+            let high_score = match kind {
+                "score" => HighScore::Score(value.min(u32::MAX as u64) as u32),
+                "time" => HighScore::Time(Duration::from_millis(value)),
+                _ => continue,
+            };
+            high_scores.insert(mode, mutators, high_score);
+        }
+        high_scores
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn load() -> Self {
+        std::fs::read_to_string(std::path::Path::new(SAVE_DIR).join(HIGH_SCORES_FILE))
+            .map(|content| Self::from_lines(&content))
+            .unwrap_or_default()
+    }
+
+    #[cfg(target_family = "wasm")]
+    fn load() -> Self {
+        Self::default()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn save(&self) -> std::io::Result<()> {
+        std::fs::create_dir_all(SAVE_DIR)?;
+        std::fs::write(
+            std::path::Path::new(SAVE_DIR).join(HIGH_SCORES_FILE),
+            self.to_lines(),
+        )
+    }
+
+    /// No file system on the web, high scores only last for the session.
+    #[cfg(target_family = "wasm")]
+    fn save(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct ObjectiveValueUi;
+
+fn reset_kills(mut kills: ResMut<Kills>) {
+    kills.0 = 0;
+}
+
+fn count_kills(event: On<EntityDied>, mut kills: ResMut<Kills>, enemies: Query<(), With<Enemy>>) {
+    if event.cause.is_player_attack() && enemies.contains(event.entity) {
+        kills.0 += 1;
+    }
+}
+
+/// End the run once the mode's goal is reached, player death ends any mode.
+fn check_game_mode_over(
+    mode: Res<RunGameMode>,
+    settings: Res<GameModeSettings>,
+    survival_time: Res<SurvivalTimer>,
+    kills: Res<Kills>,
+    mut next_state: ResMut<NextState<GameOver>>,
+) {
+    let is_over = match mode.0 {
+        GameMode::Endless => false,
+        GameMode::TimedArcade => survival_time.0.elapsed() >= settings.arcade_duration,
+        GameMode::KillTarget => kills.0 >= settings.kill_target,
+    };
+    if is_over {
+        next_state.set(GameOver(true));
+    }
+}
+
+fn record_high_score(
+    mode: Res<RunGameMode>,
+    settings: Res<GameModeSettings>,
+    score: Res<Score>,
+    survival_time: Res<SurvivalTimer>,
+    kills: Res<Kills>,
+    mutators: Res<RunMutators>,
+    mut high_scores: ResMut<HighScores>,
+) {
+    let high_score = match mode.0 {
+        GameMode::Endless | GameMode::TimedArcade => HighScore::Score(score.0),
+        // Dying before reaching the target doesn't count
+        GameMode::KillTarget if kills.0 >= settings.kill_target => {
+            HighScore::Time(survival_time.0.elapsed())
+        },
+        GameMode::KillTarget => return,
+    };
+    high_scores.insert(mode.0, mutators.0, high_score);
+    if let Err(err) = high_scores.save() {
+        warn!("Failed to save high scores: {err}");
+    }
+}
+
+fn render_objective(
+    mode: Res<RunGameMode>,
+    settings: Res<GameModeSettings>,
+    survival_time: Res<SurvivalTimer>,
+    kills: Res<Kills>,
+    query: Query<&mut TextSpan, With<ObjectiveValueUi>>,
+) {
+    let text = match mode.0 {
+        GameMode::Endless => "Survive".to_string(),
+        GameMode::TimedArcade => format!(
+            "{} left",
+            format_time(
+                settings
+                    .arcade_duration
+                    .saturating_sub(survival_time.0.elapsed())
+            )
+        ),
+        GameMode::KillTarget => format!("{}/{} kills", kills.0, settings.kill_target),
+    };
+
+    for mut ui_text in query {
+        if ui_text.0 != text {
+            ui_text.0.clone_from(&text);
+        }
+    }
+}
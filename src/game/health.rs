@@ -1,11 +1,20 @@
-use crate::{AppSystems, GameplaySet, game::player::Player};
+use crate::{AppSystems, GameplaySet, game::player::Player, screens::Screen};
 use bevy::prelude::*;
+use std::time::Duration;
 
 pub fn plugin(app: &mut App) {
+    app.add_observer(spawn_damaged_popup)
+        .add_observer(spawn_healed_popup);
+
     app.add_systems(
         Update,
-        (sync_alive_dead, handle_dead_entities, despawn_entities)
-            .chain()
+        (tick_health_regen, update_health_popups)
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    )
+    .add_systems(
+        Update,
+        sync_alive_dead
             .in_set(AppSystems::Update)
             .in_set(GameplaySet),
     )
@@ -18,7 +27,14 @@ pub fn plugin(app: &mut App) {
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
 #[require(Alive)]
-pub struct Health(u32);
+pub struct Health {
+    current:  u32,
+    max:      u32,
+    /// Additional health allowed above `max` when overhealing
+    overheal: u32,
+    /// Any damage is fatal
+    fragile:  bool,
+}
 
 #[derive(Component, Reflect, Clone, Copy, Default)]
 #[reflect(Component)]
@@ -32,17 +48,233 @@ pub struct Dead;
 #[reflect(Component)]
 pub struct HealthValueUi;
 
+/// Heals `amount` health every `interval`.
+/// Stops and removes itself once `remaining` health was regenerated, if set.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct HealthRegen {
+    pub amount:    u32,
+    pub interval:  Timer,
+    /// Total health left to regenerate, regenerates indefinitely if `None`
+    pub remaining: Option<u32>,
+    /// Allow regenerating above max health, up to the overheal cap
+    pub overheal:  bool,
+    /// Extra health regenerated on top of `amount`, one per interval and allowed to overheal.
+    /// Lets an indefinite regen take a burst without losing its own rate.
+    pub bonus:     u32,
+}
+
+/// Floating text above the player showing health changes.
+#[derive(Component)]
+struct HealthPopup(Timer);
+
+/// What dealt damage to an entity.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DamageCause {
+    Bullet,
+    /// Explosion of an explosive bullet
+    Explosion,
+    EnemyContact,
+    /// Shockwave attack of a boss
+    Shockwave,
+    /// Hazard trail left by an elite enemy
+    Hazard,
+}
+
+impl DamageCause {
+    /// Whether the damage came from the player, so kills with it count for score and statistics.
+    pub fn is_player_attack(&self) -> bool {
+        matches!(self, Self::Bullet | Self::Explosion)
+    }
+}
+
+/// Triggered after an entity's [`Health`] was damaged, see [`deal_damage`].
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct DamageDealt {
+    #[event_target]
+    pub target: Entity,
+    /// Entity that dealt the damage, if any
+    pub source: Option<Entity>,
+    pub amount: u32,
+    pub cause:  DamageCause,
+}
+
+/// Triggered when damage brings an entity's [`Health`] down to zero.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct EntityDied {
+    pub entity: Entity,
+    pub cause:  DamageCause,
+}
+
+/// Triggered after an entity's [`Health`] was healed.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct Healed {
+    pub entity: Entity,
+    pub amount: u32,
+}
+
 impl Health {
     pub fn new(health: u32) -> Self {
-        Self(health)
+        Self {
+            current:  health,
+            max:      health,
+            overheal: 0,
+            fragile:  false,
+        }
+    }
+
+    pub fn with_overheal(mut self, overheal: u32) -> Self {
+        self.overheal = overheal;
+        self
+    }
+
+    pub fn set_fragile(&mut self, fragile: bool) {
+        self.fragile = fragile;
+    }
+
+    /// Same health with current and max health multiplied by `factor`, at least 1.
+    pub fn scaled(&self, factor: f32) -> Self {
+        let scale = |health: u32| ((health as f32 * factor).round() as u32).max(1);
+        Self {
+            current:  scale(self.current),
+            max:      scale(self.max),
+            overheal: self.overheal,
+            fragile:  self.fragile,
+        }
+    }
+
+    pub fn current(&self) -> u32 {
+        self.current
+    }
+
+    pub fn max(&self) -> u32 {
+        self.max
     }
 
     pub fn is_alive(&self) -> bool {
-        self.0 > 0
+        self.current > 0
+    }
+
+    /// Returns the amount of damage actually dealt.
+    pub fn damage(&mut self, amount: u32) -> u32 {
+        let amount = if self.fragile && amount > 0 {
+            self.current
+        } else {
+            amount
+        };
+        let prev = self.current;
+        self.current = self.current.saturating_sub(amount);
+        prev - self.current
+    }
+
+    /// Heals up to max health, returns the amount actually healed.
+    pub fn heal(&mut self, amount: u32) -> u32 {
+        self.heal_up_to(amount, self.max)
+    }
+
+    /// Heals up to max health plus overheal cap, returns the amount actually healed.
+    pub fn overheal(&mut self, amount: u32) -> u32 {
+        self.heal_up_to(amount, self.max + self.overheal)
+    }
+
+    fn heal_up_to(&mut self, amount: u32, cap: u32) -> u32 {
+        let prev = self.current;
+        if prev < cap {
+            self.current = (prev + amount).min(cap);
+        }
+        self.current - prev
+    }
+}
+
+impl DamageDealt {
+    pub fn new(target: Entity, amount: u32, cause: DamageCause) -> Self {
+        Self {
+            target,
+            source: None,
+            amount,
+            cause,
+        }
     }
 
-    pub fn damage(&mut self, amount: u32) {
-        self.0 = self.0.saturating_sub(amount);
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+/// Applies `damage` to the target's `health`, then triggers [`DamageDealt`]
+/// with the amount actually dealt, and [`EntityDied`] if the damage was fatal.
+pub fn deal_damage(commands: &mut Commands, health: &mut Health, damage: DamageDealt) {
+    let was_alive = health.is_alive();
+    let amount = health.damage(damage.amount);
+    if amount == 0 {
+        return;
+    }
+
+    commands.trigger(DamageDealt { amount, ..damage });
+
+    if was_alive && !health.is_alive() {
+        commands.trigger(EntityDied {
+            entity: damage.target,
+            cause:  damage.cause,
+        });
+    }
+}
+
+impl HealthRegen {
+    /// Regenerates `total` health, `amount` at a time every `interval`.
+    pub fn burst(total: u32, amount: u32, interval: Duration) -> Self {
+        Self {
+            amount,
+            interval: Timer::new(interval, TimerMode::Repeating),
+            remaining: Some(total),
+            overheal: false,
+            bonus: 0,
+        }
+    }
+
+    pub fn with_overheal(mut self) -> Self {
+        self.overheal = true;
+        self
+    }
+}
+
+fn tick_health_regen(
+    mut commands: Commands,
+    time: Res<Time>,
+    query: Query<(Entity, &mut HealthRegen, &mut Health), Without<Dead>>,
+) {
+    let delta = time.delta();
+    for (entity, mut regen, mut health) in query {
+        regen.interval.tick(delta);
+
+        for _ in 0 .. regen.interval.times_finished_this_tick() {
+            let amount = regen
+                .remaining
+                .map_or(regen.amount, |remaining| regen.amount.min(remaining));
+            let mut healed = if regen.overheal {
+                health.overheal(amount)
+            } else {
+                health.heal(amount)
+            };
+            if regen.bonus > 0 {
+                regen.bonus -= 1;
+                healed += health.overheal(1);
+            }
+            if healed > 0 {
+                commands.trigger(Healed {
+                    entity,
+                    amount: healed,
+                });
+            }
+            if let Some(remaining) = regen.remaining.as_mut() {
+                *remaining -= amount;
+            }
+        }
+
+        if regen.remaining == Some(0) {
+            commands.entity(entity).remove::<HealthRegen>();
+        }
     }
 }
 
@@ -64,32 +296,71 @@ fn sync_alive_dead(
     }
 }
 
-fn handle_dead_entities(time: Res<Time>, dead_query: Query<&mut Transform, With<Dead>>) {
-    let dt = time.delta_secs();
-    let delta = 1.0 * dt;
-    for mut transform in dead_query {
-        transform.scale = Vec3::new(
-            transform.scale.x - delta,
-            transform.scale.y - delta,
-            transform.scale.z,
-        )
-        .max(Vec3::ZERO);
+fn render_health(
+    health: Single<&Health, (Changed<Health>, With<Player>)>,
+    query: Query<&mut TextSpan, With<HealthValueUi>>,
+) {
+    for mut ui_text in query {
+        ui_text.0 = format!("{}/{}", health.current(), health.max());
+    }
+}
+
+const HEALTH_POPUP_DURATION: f32 = 0.8;
+const HEALTH_POPUP_RISE_SPEED: f32 = 16.0;
+
+fn spawn_damaged_popup(
+    event: On<DamageDealt>,
+    mut commands: Commands,
+    players: Query<&GlobalTransform, With<Player>>,
+) {
+    if let Ok(transform) = players.get(event.target) {
+        commands.spawn(health_popup(
+            format!("-{}", event.amount),
+            Color::hsl(0.0, 0.9, 0.6),
+            transform.translation(),
+        ));
     }
 }
 
-fn despawn_entities(mut commands: Commands, dead_query: Query<(Entity, &Transform), With<Dead>>) {
-    for (entity, transform) in dead_query {
-        if transform.scale.x <= 0.0 || transform.scale.y <= 0.0 {
-            commands.entity(entity).despawn();
-        }
+fn spawn_healed_popup(
+    event: On<Healed>,
+    mut commands: Commands,
+    players: Query<&GlobalTransform, With<Player>>,
+) {
+    if let Ok(transform) = players.get(event.entity) {
+        commands.spawn(health_popup(
+            format!("+{}", event.amount),
+            Color::hsl(120.0, 0.9, 0.6),
+            transform.translation(),
+        ));
     }
 }
 
-fn render_health(
-    health: Single<&Health, (Changed<Health>, With<Player>)>,
-    query: Query<&mut TextSpan, With<HealthValueUi>>,
+fn health_popup(text: String, color: Color, translation: Vec3) -> impl Bundle {
+    (
+        Name::new("HealthPopup"),
+        HealthPopup(Timer::from_seconds(HEALTH_POPUP_DURATION, TimerMode::Once)),
+        Text2d::new(text),
+        // Render at a larger font size and scale down, so text stays crisp when zoomed in.
+        TextFont::from_font_size(32.0),
+        TextColor(color),
+        Transform::from_translation(translation + Vec3::new(0.0, 12.0, 10.0))
+            .with_scale(Vec3::splat(0.25)),
+        DespawnOnExit(Screen::Gameplay),
+    )
+}
+
+fn update_health_popups(
+    mut commands: Commands,
+    time: Res<Time>,
+    popups: Query<(Entity, &mut HealthPopup, &mut Transform, &mut TextColor)>,
 ) {
-    for mut ui_text in query {
-        ui_text.0 = health.0.to_string();
+    for (entity, mut popup, mut transform, mut color) in popups {
+        popup.0.tick(time.delta());
+        transform.translation.y += HEALTH_POPUP_RISE_SPEED * time.delta_secs();
+        color.0.set_alpha(popup.0.fraction_remaining());
+        if popup.0.is_finished() {
+            commands.entity(entity).despawn();
+        }
     }
 }
@@ -0,0 +1,270 @@
+//! Minimap in the bottom right corner: a texture generated from the level's solid colliders,
+//! with blips for the player, the bullet and enemies.
+
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        bullet::Bullet,
+        enemy::{Enemy, EnemyVariant},
+        player::Player,
+        solid::Solid,
+    },
+    screens::Screen,
+};
+use avian2d::prelude::*;
+use bevy::{
+    asset::RenderAssetUsages,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<MinimapSettings>()
+        .init_resource::<MinimapBounds>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), (reset_minimap, spawn_minimap));
+
+    app.add_systems(
+        Update,
+        (
+            generate_minimap_texture.run_if(resource_equals(MinimapBounds(None))),
+            (spawn_blips, update_blips).chain(),
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+    app.add_systems(
+        Update,
+        apply_minimap_settings
+            .run_if(resource_changed::<MinimapSettings>)
+            .in_set(AppSystems::Update),
+    );
+}
+
+/// Pixels along the longer side of the generated minimap texture.
+const TEXTURE_RESOLUTION: u32 = 128;
+const MINIMAP_MARGIN: f32 = 16.0;
+const BLIP_SIZE: f32 = 4.0;
+const BOSS_BLIP_SIZE: f32 = 10.0;
+
+/// #fcfbcc
+const WALL_COLOR: Color = Color::srgb(0.988, 0.984, 0.800);
+const BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5);
+
+#[derive(Resource, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Resource)]
+pub struct MinimapSettings {
+    pub enabled: bool,
+    /// Width of the minimap in UI pixels, the height follows the map's aspect ratio
+    pub size:    f32,
+}
+
+impl MinimapSettings {
+    pub const MIN_SIZE: f32 = 100.0;
+    pub const MAX_SIZE: f32 = 400.0;
+    pub const SIZE_STEP: f32 = 25.0;
+}
+
+impl Default for MinimapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            size:    200.0,
+        }
+    }
+}
+
+/// World space area shown by the minimap, once its texture was generated.
+#[derive(Resource, Clone, Copy, PartialEq, Default)]
+struct MinimapBounds(Option<Rect>);
+
+/// UI node displaying the minimap texture, parent of all blips.
+#[derive(Component)]
+struct Minimap;
+
+/// Shows the position of the tracked entity on the [`Minimap`].
+#[derive(Component)]
+struct MinimapBlip(Entity);
+
+fn reset_minimap(mut bounds: ResMut<MinimapBounds>) {
+    bounds.0 = None;
+}
+
+fn spawn_minimap(mut commands: Commands, settings: Res<MinimapSettings>) {
+    commands.spawn((
+        Name::new("Minimap"),
+        Minimap,
+        Node {
+            position_type: PositionType::Absolute,
+            right: px(MINIMAP_MARGIN),
+            bottom: px(MINIMAP_MARGIN),
+            width: px(settings.size),
+            height: px(settings.size),
+            border: UiRect::all(px(2)),
+            ..default()
+        },
+        BackgroundColor(BACKGROUND_COLOR),
+        BorderColor::all(WALL_COLOR),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+/// Rasterize the solid colliders once they are all in place.
+fn generate_minimap_texture(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut bounds: ResMut<MinimapBounds>,
+    settings: Res<MinimapSettings>,
+    solids: Query<(&Collider, &ColliderAabb, &GlobalTransform), With<Solid>>,
+    minimap: Single<(Entity, &mut Node, &mut Visibility), With<Minimap>>,
+) {
+    if solids.is_empty() || solids.iter().any(|(_, aabb, _)| aabb.size() == Vec2::ZERO) {
+        return;
+    }
+    let Some(rect) = solids
+        .iter()
+        .map(|(_, aabb, _)| Rect::from_corners(aabb.min, aabb.max))
+        .reduce(|bounds, rect| bounds.union(rect))
+    else {
+        return;
+    };
+
+    let size = rect.size();
+    let pixel_size = size.max_element() / TEXTURE_RESOLUTION as f32;
+    let width = (size.x / pixel_size).ceil().max(1.0) as u32;
+    let height = (size.y / pixel_size).ceil().max(1.0) as u32;
+
+    let mut image = Image::new_fill(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    for y in 0 .. height {
+        for x in 0 .. width {
+            // Image rows go top to bottom, world y goes up
+            let point = Vec2::new(
+                rect.min.x + (x as f32 + 0.5) * pixel_size,
+                rect.max.y - (y as f32 + 0.5) * pixel_size,
+            );
+            let is_wall = solids.iter().any(|(collider, aabb, transform)| {
+                Rect::from_corners(aabb.min, aabb.max)
+                    .inflate(pixel_size)
+                    .contains(point)
+                    && collider.distance_to_point(
+                        Position(transform.translation().truncate()),
+                        Rotation::from(transform.rotation()),
+                        point,
+                        true,
+                    ) <= pixel_size * 0.5
+            });
+            if is_wall && let Err(err) = image.set_color_at(x, y, WALL_COLOR) {
+                warn!("Failed to draw minimap pixel: {err}");
+                return;
+            }
+        }
+    }
+
+    let (entity, mut node, mut visibility) = minimap.into_inner();
+    commands
+        .entity(entity)
+        .insert(ImageNode::new(images.add(image)));
+    node.height = px(settings.size * size.y / size.x);
+    *visibility = if settings.enabled {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    bounds.0 = Some(rect);
+}
+
+fn spawn_blips(
+    mut commands: Commands,
+    minimap: Single<Entity, With<Minimap>>,
+    tracked: Query<
+        (Entity, Has<Player>, Has<Bullet>, Option<&EnemyVariant>),
+        Or<(Added<Player>, Added<Bullet>, Added<Enemy>)>,
+    >,
+) {
+    for (entity, is_player, is_bullet, variant) in tracked {
+        let (color, size) = if is_player {
+            (Color::hsl(120.0, 0.9, 0.6), BLIP_SIZE * 1.5)
+        } else if is_bullet {
+            (Color::hsl(50.0, 0.9, 0.6), BLIP_SIZE)
+        } else if variant == Some(&EnemyVariant::Boss) {
+            (Color::hsl(0.0, 0.9, 0.5), BOSS_BLIP_SIZE)
+        } else {
+            (Color::hsl(0.0, 0.9, 0.5), BLIP_SIZE)
+        };
+
+        commands.spawn((
+            Name::new("Minimap Blip"),
+            MinimapBlip(entity),
+            Node {
+                position_type: PositionType::Absolute,
+                width: px(size),
+                height: px(size),
+                // Centered on its position
+                margin: UiRect {
+                    left: px(-size * 0.5),
+                    top: px(-size * 0.5),
+                    ..default()
+                },
+                ..default()
+            },
+            BackgroundColor(color),
+            BorderRadius::MAX,
+            // The player is drawn above everything else
+            ZIndex(if is_player { 1 } else { 0 }),
+            Pickable::IGNORE,
+            ChildOf(*minimap),
+        ));
+    }
+}
+
+fn update_blips(
+    mut commands: Commands,
+    bounds: Res<MinimapBounds>,
+    blips: Query<(Entity, &MinimapBlip, &mut Node)>,
+    targets: Query<&GlobalTransform>,
+) {
+    let Some(rect) = bounds.0 else {
+        return;
+    };
+    for (entity, blip, mut node) in blips {
+        let Ok(transform) = targets.get(blip.0) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let position = ((transform.translation().truncate() - rect.min) / rect.size())
+            .clamp(Vec2::ZERO, Vec2::ONE);
+        node.left = percent(position.x * 100.0);
+        node.top = percent((1.0 - position.y) * 100.0);
+    }
+}
+
+fn apply_minimap_settings(
+    settings: Res<MinimapSettings>,
+    bounds: Res<MinimapBounds>,
+    minimaps: Query<(&mut Node, &mut Visibility), With<Minimap>>,
+) {
+    let Some(rect) = bounds.0 else {
+        return;
+    };
+    for (mut node, mut visibility) in minimaps {
+        node.width = px(settings.size);
+        node.height = px(settings.size * rect.height() / rect.width());
+        *visibility = if settings.enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
@@ -1,16 +1,29 @@
 use bevy::prelude::*;
 
+pub mod achievements;
 pub mod aim;
 pub mod animation;
+pub mod boss;
 pub mod bullet;
+pub mod daily;
+pub mod dash;
+pub mod death;
 pub mod decoration;
+pub mod difficulty;
 pub mod enemy;
+pub mod game_mode;
 pub mod health;
 pub mod level;
+pub mod minimap;
 pub mod movement;
+pub mod mutators;
+pub mod particles;
 pub mod player;
+pub mod run_rng;
+pub mod run_stats;
 pub mod score;
 pub mod solid;
+pub mod statistics;
 pub mod survival_timer;
 pub mod visual_scaling;
 pub mod visuals;
@@ -33,7 +46,23 @@ pub(super) fn plugin(app: &mut App) {
         survival_timer::plugin,
         waves::plugin,
         health::plugin,
+        death::plugin,
         decoration::plugin,
     ))
-    .add_plugins((score::plugin, visual_scaling::plugin));
+    .add_plugins((
+        score::plugin,
+        visual_scaling::plugin,
+        particles::plugin,
+        dash::plugin,
+        boss::plugin,
+        difficulty::plugin,
+        game_mode::plugin,
+        daily::plugin,
+        mutators::plugin,
+        statistics::plugin,
+        achievements::plugin,
+        run_stats::plugin,
+        minimap::plugin,
+        run_rng::plugin,
+    ));
 }
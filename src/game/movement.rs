@@ -1,13 +1,14 @@
 use crate::{
     AppSystems,
     direction::Direction,
-    game::health::Dead,
-    game_state::ActiveGameplaySet,
+    game::{dash::Dashing, health::Dead},
+    game_state::{ActiveGameplaySet, GameplaySet},
     input::{ActionState, PlayerAction},
 };
 use avian2d::{math::Scalar, prelude::*};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
@@ -16,11 +17,17 @@ pub(super) fn plugin(app: &mut App) {
             .in_set(AppSystems::Update)
             .in_set(ActiveGameplaySet),
     );
+    app.add_systems(
+        Update,
+        (tick_stunned, tick_movement_modifiers)
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
 }
 
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
-#[require(Acceleration(1000.0), WalkDirection)]
+#[require(Acceleration(1000.0), WalkDirection, MovementModifiers)]
 pub struct MovementController;
 
 #[derive(Component, Reflect, Serialize, Deserialize)]
@@ -31,12 +38,71 @@ pub struct Acceleration(pub Scalar);
 #[reflect(Component)]
 pub struct WalkDirection(pub Option<Direction>);
 
+/// Multiplicative modifiers of a [`MovementController`]'s acceleration.
+/// Each [`ModifierSource`] contributes at most one modifier.
+#[derive(Component, Reflect, Clone, Default, Debug)]
+#[reflect(Component)]
+pub struct MovementModifiers(Vec<MovementModifier>);
+
+#[derive(Reflect, Clone, Debug)]
+pub struct MovementModifier {
+    pub source:     ModifierSource,
+    pub multiplier: Scalar,
+    /// Removes the modifier once finished, lasts until removed manually if `None`
+    pub timer:      Option<Timer>,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModifierSource {
+    /// Carrying the bullet slows down, being without it speeds up
+    Bullet,
+    /// Collected a bullet type pickup
+    Pickup,
+    /// Hit by an enemy
+    EnemySlow,
+}
+
+impl MovementModifiers {
+    /// Add a modifier, replacing any existing modifier from the same source.
+    pub fn set(&mut self, source: ModifierSource, multiplier: Scalar, duration: Option<Duration>) {
+        self.remove(source);
+        self.0.push(MovementModifier {
+            source,
+            multiplier,
+            timer: duration.map(|duration| Timer::new(duration, TimerMode::Once)),
+        });
+    }
+
+    pub fn remove(&mut self, source: ModifierSource) {
+        self.0.retain(|modifier| modifier.source != source);
+    }
+
+    pub fn get(&self, source: ModifierSource) -> Option<&MovementModifier> {
+        self.0.iter().find(|modifier| modifier.source == source)
+    }
+
+    /// Combined multiplier of all modifiers.
+    pub fn multiplier(&self) -> Scalar {
+        self.0.iter().map(|modifier| modifier.multiplier).product()
+    }
+}
+
+/// Disables movement controls until the timer finishes.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Stunned(pub Timer);
+
 fn apply_movement(
     time: Res<Time>,
     action_state: Res<ActionState<PlayerAction>>,
     mut controllers: Query<
-        (&Acceleration, &mut LinearVelocity),
-        (With<MovementController>, Without<Dead>),
+        (&Acceleration, &MovementModifiers, &mut LinearVelocity),
+        (
+            With<MovementController>,
+            Without<Dead>,
+            Without<Stunned>,
+            Without<Dashing>,
+        ),
     >,
 ) {
     const DEADZONE: Scalar = 0.1;
@@ -51,8 +117,34 @@ fn apply_movement(
         return;
     }
 
-    for (movement_acceleration, mut linear_velocity) in &mut controllers {
-        **linear_velocity += direction * movement_acceleration.0 * delta_time;
+    for (movement_acceleration, modifiers, mut linear_velocity) in &mut controllers {
+        **linear_velocity +=
+            direction * movement_acceleration.0 * modifiers.multiplier() * delta_time;
+    }
+}
+
+fn tick_stunned(mut commands: Commands, time: Res<Time>, query: Query<(Entity, &mut Stunned)>) {
+    for (entity, mut stunned) in query {
+        stunned.0.tick(time.delta());
+        if stunned.0.is_finished() {
+            commands.entity(entity).remove::<Stunned>();
+        }
+    }
+}
+
+fn tick_movement_modifiers(time: Res<Time>, query: Query<&mut MovementModifiers>) {
+    for mut modifiers in query {
+        if modifiers.0.iter().all(|modifier| modifier.timer.is_none()) {
+            continue;
+        }
+
+        modifiers.0.retain_mut(|modifier| {
+            let Some(timer) = &mut modifier.timer else {
+                return true;
+            };
+            timer.tick(time.delta());
+            !timer.is_finished()
+        });
     }
 }
 
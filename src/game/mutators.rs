@@ -0,0 +1,158 @@
+//! Optional rules altering a run, chosen before starting it and recorded with its score.
+
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        bullet::Bullet,
+        enemy::{Enemy, EnemySettings},
+        health::Health,
+        player::Player,
+    },
+};
+use avian2d::{math::Scalar, prelude::*};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunMutators>();
+
+    app.add_systems(
+        Update,
+        (
+            double_enemy_speed.run_if(mutator_active(Mutator::DoubleEnemySpeed)),
+            remove_bullet_damping.run_if(mutator_active(Mutator::EndlessBullet)),
+            remove_friction.run_if(mutator_active(Mutator::ZeroFriction)),
+            grow_bullets.run_if(mutator_active(Mutator::GiantBullet)),
+            make_player_fragile.run_if(mutator_active(Mutator::OneHitDeath)),
+        )
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+const ENEMY_SPEED_MULTIPLIER: Scalar = 2.0;
+const GIANT_BULLET_SCALE: f32 = 3.0;
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mutator {
+    DoubleEnemySpeed,
+    /// The bullet keeps its speed after it stops being dangerous
+    EndlessBullet,
+    /// Any damage kills the player
+    OneHitDeath,
+    /// No linear damping on the player and enemies
+    ZeroFriction,
+    GiantBullet,
+}
+
+impl Mutator {
+    pub const ALL: [Self; 5] = [
+        Self::DoubleEnemySpeed,
+        Self::EndlessBullet,
+        Self::OneHitDeath,
+        Self::ZeroFriction,
+        Self::GiantBullet,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DoubleEnemySpeed => "Double enemy speed",
+            Self::EndlessBullet => "Bullet never stops",
+            Self::OneHitDeath => "One-hit death",
+            Self::ZeroFriction => "Zero friction",
+            Self::GiantBullet => "Giant bullet",
+        }
+    }
+
+    fn bit(&self) -> u8 {
+        1 << *self as u8
+    }
+}
+
+/// Set of [`Mutator`]s, compared as a whole when ranking scores.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Mutators(u8);
+
+impl Mutators {
+    pub fn contains(&self, mutator: Mutator) -> bool {
+        self.0 & mutator.bit() != 0
+    }
+
+    pub fn set(&mut self, mutator: Mutator, enabled: bool) {
+        if enabled {
+            self.0 |= mutator.bit();
+        } else {
+            self.0 &= !mutator.bit();
+        }
+    }
+
+    /// Enabled mutators joined by `+`, or `-` if none are, as saved with high scores.
+    pub fn key(&self) -> String {
+        let key = Mutator::ALL
+            .iter()
+            .filter(|mutator| self.contains(**mutator))
+            .map(|mutator| format!("{mutator:?}"))
+            .collect::<Vec<_>>()
+            .join("+");
+        if key.is_empty() { "-".to_string() } else { key }
+    }
+
+    /// Parse a key written by [`Mutators::key`], `None` if it names unknown mutators.
+    pub fn from_key(key: &str) -> Option<Self> {
+        let mut mutators = Self::default();
+        for name in key.split('+').filter(|name| *name != "-") {
+            let mutator = Mutator::ALL
+                .into_iter()
+                .find(|mutator| format!("{mutator:?}") == name)?;
+            mutators.set(mutator, true);
+        }
+        Some(mutators)
+    }
+}
+
+/// Mutators of the current or next run.
+#[derive(Resource, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Resource)]
+pub struct RunMutators(pub Mutators);
+
+fn mutator_active(mutator: Mutator) -> impl Fn(Res<RunMutators>) -> bool {
+    move |mutators: Res<RunMutators>| mutators.0.contains(mutator)
+}
+
+fn double_enemy_speed(enemies: Query<&mut EnemySettings, (With<Enemy>, Added<EnemySettings>)>) {
+    for mut settings in enemies {
+        settings.speed *= ENEMY_SPEED_MULTIPLIER;
+    }
+}
+
+fn remove_bullet_damping(
+    bullets: Query<&mut LinearDamping, (With<Bullet>, Changed<LinearDamping>)>,
+) {
+    for mut damping in bullets {
+        if damping.0 != 0.0 {
+            damping.0 = 0.0;
+        }
+    }
+}
+
+fn remove_friction(
+    bodies: Query<&mut LinearDamping, (Or<(With<Player>, With<Enemy>)>, Changed<LinearDamping>)>,
+) {
+    for mut damping in bodies {
+        if damping.0 != 0.0 {
+            damping.0 = 0.0;
+        }
+    }
+}
+
+fn grow_bullets(bullets: Query<&mut Transform, Added<Bullet>>) {
+    for mut transform in bullets {
+        transform.scale *= GIANT_BULLET_SCALE;
+    }
+}
+
+/// Any damage taken kills the player, applied by [`Health::damage`] so it's a single hit.
+fn make_player_fragile(players: Query<&mut Health, (With<Player>, Added<Health>)>) {
+    for mut health in players {
+        health.set_fragile(true);
+    }
+}
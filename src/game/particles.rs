@@ -0,0 +1,295 @@
+//! Lightweight CPU particles.
+//! Particle entities are pooled and reused, so bursts of hundreds of particles stay cheap.
+
+use crate::{AppSystems, GameplaySet, game::visuals::VisualIntensity, screens::Screen};
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::TAU;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<ParticlePool>();
+
+    app.add_systems(OnExit(Screen::Gameplay), clear_particle_pool);
+
+    app.add_systems(
+        Update,
+        (emit_particles, update_particles)
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Upper limit of simultaneously active particles, further particles are dropped.
+const MAX_PARTICLES: usize = 1024;
+
+/// Emits particles from the entity's position.
+/// [`EmitterMode::Burst`] emitters emit once and are then removed,
+/// [`EmitterMode::Continuous`] emitters emit for as long as they exist.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct ParticleEmitter {
+    pub mode:      EmitterMode,
+    /// Min and max particle lifetime in seconds
+    pub lifetime:  (f32, f32),
+    /// Min and max initial particle speed
+    pub speed:     (f32, f32),
+    /// Direction the emission cone is centered on
+    pub direction: Vec2,
+    /// Full angle of the emission cone in radians, `TAU` emits in all directions
+    pub spread:    f32,
+    /// Velocity damping per second
+    pub damping:   f32,
+    pub size:      f32,
+    pub color:     ParticleColor,
+    #[reflect(ignore)]
+    accumulator:   f32,
+}
+
+#[derive(Reflect, Clone, Copy, Debug)]
+pub enum EmitterMode {
+    /// Emit this many particles at once
+    Burst(u32),
+    /// Emit this many particles per second
+    Continuous(f32),
+}
+
+/// Color of a particle over its lifetime.
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct ParticleColor {
+    pub start:     Color,
+    pub end:       Color,
+    /// Hue rotation in degrees over the particle's lifetime, scaled by [`VisualIntensity`]
+    pub hue_shift: f32,
+}
+
+impl ParticleColor {
+    /// Fades out from the given color.
+    pub fn fade(color: Color) -> Self {
+        Self {
+            start:     color,
+            end:       color.with_alpha(0.0),
+            hue_shift: 0.0,
+        }
+    }
+
+    fn at(&self, t: f32, intensity: f32) -> Color {
+        self.start
+            .mix(&self.end, t)
+            .rotate_hue(self.hue_shift * t * intensity)
+    }
+}
+
+impl ParticleEmitter {
+    pub fn burst(amount: u32) -> Self {
+        Self::new(EmitterMode::Burst(amount))
+    }
+
+    pub fn continuous(rate: f32) -> Self {
+        Self::new(EmitterMode::Continuous(rate))
+    }
+
+    fn new(mode: EmitterMode) -> Self {
+        Self {
+            mode,
+            lifetime: (0.3, 0.8),
+            speed: (20.0, 60.0),
+            direction: Vec2::X,
+            spread: TAU,
+            damping: 4.0,
+            size: 2.0,
+            color: ParticleColor::fade(Color::WHITE),
+            accumulator: 0.0,
+        }
+    }
+
+    pub fn with_lifetime(mut self, min: f32, max: f32) -> Self {
+        self.lifetime = (min, max);
+        self
+    }
+
+    pub fn with_speed(mut self, min: f32, max: f32) -> Self {
+        self.speed = (min, max);
+        self
+    }
+
+    pub fn with_cone(mut self, direction: Vec2, spread: f32) -> Self {
+        self.direction = direction.normalize_or(Vec2::X);
+        self.spread = spread;
+        self
+    }
+
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_color(mut self, color: ParticleColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn particle(&self, rng: &mut impl Rng) -> Particle {
+        let angle =
+            self.direction.to_angle() + rng.random_range(-0.5 .. 0.5) * self.spread.clamp(0.0, TAU);
+        let speed = random_between(rng, self.speed);
+        Particle {
+            alive:    true,
+            velocity: Vec2::from_angle(angle) * speed,
+            damping:  self.damping,
+            age:      0.0,
+            lifetime: random_between(rng, self.lifetime).max(f32::EPSILON),
+            color:    self.color,
+        }
+    }
+}
+
+/// Standalone burst of particles at the given position, the emitter entity despawns itself.
+pub fn particle_burst(emitter: ParticleEmitter, translation: Vec3) -> impl Bundle {
+    (
+        Name::new("ParticleBurst"),
+        ParticleBurst,
+        emitter,
+        Transform::from_translation(translation),
+        DespawnOnExit(Screen::Gameplay),
+    )
+}
+
+#[derive(Component)]
+struct ParticleBurst;
+
+#[derive(Component)]
+struct Particle {
+    alive:    bool,
+    velocity: Vec2,
+    damping:  f32,
+    age:      f32,
+    lifetime: f32,
+    color:    ParticleColor,
+}
+
+/// Inactive particle entities, ready to be reused.
+#[derive(Resource, Default)]
+struct ParticlePool {
+    free:   Vec<Entity>,
+    active: usize,
+}
+
+impl ParticlePool {
+    fn spawn(&mut self, commands: &mut Commands, particle: Particle, sprite: Sprite, at: Vec3) {
+        if self.active >= MAX_PARTICLES {
+            return;
+        }
+        self.active += 1;
+
+        let bundle = (
+            particle,
+            sprite,
+            Transform::from_translation(at),
+            Visibility::Inherited,
+        );
+        if let Some(entity) = self.free.pop() {
+            commands.entity(entity).try_insert(bundle);
+        } else {
+            commands.spawn((
+                Name::new("Particle"),
+                bundle,
+                DespawnOnExit(Screen::Gameplay),
+            ));
+        }
+    }
+}
+
+fn clear_particle_pool(mut pool: ResMut<ParticlePool>) {
+    *pool = ParticlePool::default();
+}
+
+fn emit_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool: ResMut<ParticlePool>,
+    emitters: Query<(
+        Entity,
+        &mut ParticleEmitter,
+        &GlobalTransform,
+        Has<ParticleBurst>,
+    )>,
+) {
+    let mut rng = rand::rng();
+
+    for (entity, mut emitter, transform, is_standalone) in emitters {
+        let amount = match emitter.mode {
+            EmitterMode::Burst(amount) => {
+                if is_standalone {
+                    commands.entity(entity).despawn();
+                } else {
+                    commands.entity(entity).remove::<ParticleEmitter>();
+                }
+                amount
+            },
+            EmitterMode::Continuous(rate) => {
+                emitter.accumulator += rate * time.delta_secs();
+                let amount = emitter.accumulator.floor();
+                emitter.accumulator -= amount;
+                amount as u32
+            },
+        };
+
+        let origin = transform.translation() + Vec3::Z;
+        for _ in 0 .. amount {
+            let particle = emitter.particle(&mut rng);
+            let sprite = Sprite::from_color(emitter.color.start, Vec2::splat(emitter.size));
+            pool.spawn(&mut commands, particle, sprite, origin);
+        }
+    }
+}
+
+fn update_particles(
+    time: Res<Time>,
+    intensity: Res<VisualIntensity>,
+    mut pool: ResMut<ParticlePool>,
+    particles: Query<(
+        Entity,
+        &mut Particle,
+        &mut Transform,
+        &mut Sprite,
+        &mut Visibility,
+    )>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut particle, mut transform, mut sprite, mut visibility) in particles {
+        if !particle.alive {
+            continue;
+        }
+
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            particle.alive = false;
+            *visibility = Visibility::Hidden;
+            pool.active = pool.active.saturating_sub(1);
+            pool.free.push(entity);
+            continue;
+        }
+
+        transform.translation += (particle.velocity * dt).extend(0.0);
+        let damping = 1.0 - (particle.damping * dt).min(1.0);
+        particle.velocity *= damping;
+        sprite.color = particle
+            .color
+            .at(particle.age / particle.lifetime, intensity.0);
+    }
+}
+
+fn random_between(rng: &mut impl Rng, (min, max): (f32, f32)) -> f32 {
+    if max > min {
+        rng.random_range(min .. max)
+    } else {
+        min
+    }
+}
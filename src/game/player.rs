@@ -5,9 +5,10 @@ use crate::{
     game::{
         aim::AimController,
         bullet::BulletSpawner,
+        dash::{DashController, Dashing},
         enemy::{Enemy, EnemyGoal, EnemySettings, EnemyStunned},
-        health::{Dead, Health},
-        movement::{Acceleration, MovementController},
+        health::{DamageCause, DamageDealt, Dead, EntityDied, Health, deal_damage},
+        movement::{Acceleration, ModifierSource, MovementController, MovementModifiers},
         util::CollisionTag,
         visuals::{AnimationDirection, HueAnimation, SetSpriteColor, VisualAnimation},
     },
@@ -17,16 +18,13 @@ use avian2d::prelude::*;
 use bevy::prelude::*;
 use bevy_aseprite_ultra::prelude::{Animation, AseAnimation, Aseprite};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 pub(super) fn plugin(app: &mut App) {
     app.load_resource::<PlayerAssets>();
 
-    app.add_systems(
-        Update,
-        handle_player_death
-            .in_set(GameplaySet)
-            .in_set(AppSystems::Update),
-    );
+    app.add_observer(handle_player_death);
+
     app.add_systems(
         PreUpdate,
         post_add_player
@@ -61,6 +59,7 @@ fn post_add_player(
     Sprite::default(),
     MovementController,
     AimController,
+    DashController,
     Acceleration(1800.0),
     LinearDamping(15.0),
     RigidBody::Dynamic,
@@ -79,7 +78,7 @@ fn post_add_player(
     LockedAxes::ROTATION_LOCKED,
     BulletSpawner,
     EnemyGoal,
-    Health::new(100),
+    Health = Health::new(100).with_overheal(20),
 
     SetSpriteColor(Color::hsl(0.0, 0.8, 0.75)),
     HueAnimation(VisualAnimation {
@@ -90,6 +89,10 @@ fn post_add_player(
 )]
 pub struct Player;
 
+/// Movement slow after being hit by an enemy.
+const ENEMY_HIT_SLOW: f32 = 0.6;
+const ENEMY_HIT_SLOW_DURATION: Duration = Duration::from_millis(500);
+
 #[derive(Component)]
 pub struct Invincible;
 
@@ -118,12 +121,15 @@ impl FromWorld for PlayerAssets {
 
 fn handle_enemy_collision(
     trigger: On<CollisionStart>,
+    mut commands: Commands,
     mut players: Query<
         (
             &GlobalTransform,
             &mut LinearVelocity,
+            &mut MovementModifiers,
             Option<&mut Health>,
             Has<Invincible>,
+            Has<Dashing>,
         ),
         (With<Player>, Without<Enemy>),
     >,
@@ -141,7 +147,7 @@ fn handle_enemy_collision(
     let enemy = trigger.collider2;
 
     if let (
-        Ok((player_transform, mut velocity, health, is_invincible)),
+        Ok((player_transform, mut velocity, mut modifiers, health, is_invincible, is_dashing)),
         Ok((enemy_transform, enemy_settings)),
     ) = (players.get_mut(player), enemies.get(enemy))
     {
@@ -150,23 +156,34 @@ fn handle_enemy_collision(
         .normalize_or_zero();
         velocity.0 += direction * enemy_settings.knockback_strength;
 
-        if is_invincible {
+        if is_invincible || is_dashing {
             return;
         }
 
+        modifiers.set(
+            ModifierSource::EnemySlow,
+            ENEMY_HIT_SLOW,
+            Some(ENEMY_HIT_SLOW_DURATION),
+        );
+
         if let Some(mut health) = health {
-            health.damage(1);
+            deal_damage(
+                &mut commands,
+                &mut health,
+                DamageDealt::new(player, 1, DamageCause::EnemyContact).with_source(enemy),
+            );
         }
     }
 }
 
 fn handle_player_death(
+    event: On<EntityDied>,
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameOver>>,
     assets: Res<PlayerAssets>,
-    dead_players: Query<(), (With<Player>, Added<Dead>)>,
+    players: Query<(), With<Player>>,
 ) {
-    if !dead_players.is_empty() {
+    if players.contains(event.entity) {
         commands.spawn(sound_effect(assets.sfx_death.clone()));
         next_state.set(GameOver(true));
     }
@@ -0,0 +1,40 @@
+//! Random number generators of a run, seeded from the date on daily runs
+//! so every player faces the same enemies.
+
+use crate::{game::daily::RunDailyChallenge, screens::Screen};
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(RunRng {
+        spawns: StdRng::from_rng(&mut rand::rng()),
+        ai:     StdRng::from_rng(&mut rand::rng()),
+    });
+
+    app.add_systems(OnEnter(Screen::Gameplay), seed_run_rng);
+}
+
+/// Mixed into the daily seed for the [`RunRng::ai`] stream.
+const AI_SEED_SALT: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[derive(Resource)]
+pub struct RunRng {
+    /// Wave layout: formations, elite rolls and boss spawn positions,
+    /// only drawn from on a fixed schedule so it stays in sync between players
+    pub spawns: StdRng,
+    /// Enemy and boss behavior, drawn from depending on frame timing and the player's actions
+    pub ai:     StdRng,
+}
+
+fn seed_run_rng(mut rng: ResMut<RunRng>, daily: Res<RunDailyChallenge>) {
+    *rng = match &daily.0 {
+        Some(daily) => RunRng {
+            spawns: StdRng::seed_from_u64(daily.seed),
+            ai:     StdRng::seed_from_u64(daily.seed ^ AI_SEED_SALT),
+        },
+        None => RunRng {
+            spawns: StdRng::from_rng(&mut rand::rng()),
+            ai:     StdRng::from_rng(&mut rand::rng()),
+        },
+    };
+}
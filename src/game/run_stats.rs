@@ -0,0 +1,257 @@
+//! Detailed statistics of the current run, shown in a breakdown once the run is over.
+
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        bullet::{BulletCollected, BulletFired, BulletHit},
+        enemy::{Enemy, EnemyVariant},
+        health::{DamageCause, DamageDealt, EntityDied},
+        player::Player,
+        score::Score,
+        survival_timer::{SurvivalTimer, format_time},
+        waves::WaveStarted,
+    },
+    game_state::GameOver,
+    screens::Screen,
+    theme::{palette::*, widget},
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<RunStats>();
+
+    app.add_observer(track_shot)
+        .add_observer(track_hit)
+        .add_observer(track_retrieval)
+        .add_observer(track_damage_taken)
+        .add_observer(track_kill)
+        .add_observer(track_wave);
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_run_stats);
+    app.add_systems(OnEnter(GameOver(true)), spawn_run_summary);
+
+    app.add_systems(
+        Update,
+        sample_score
+            .run_if(in_state(GameOver(false)))
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Survival time between two samples of the score graph.
+const SCORE_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+const GRAPH_HEIGHT: f32 = 80.0;
+const GRAPH_WIDTH: f32 = 600.0;
+/// #e07a3c
+const GRAPH_BAR: Color = Color::srgb(0.878, 0.478, 0.235);
+
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct RunStats {
+    pub shots_fired:        u32,
+    /// Shots whose bullet hit at least one enemy
+    pub shots_hit:          u32,
+    pub bullets_retrieved:  u32,
+    /// Summed time between firing and picking the bullet back up
+    pub total_retrieval:    Duration,
+    pub damage_taken:       HashMap<EnemyVariant, u32>,
+    /// Damage taken from hazards not belonging to an enemy variant
+    pub other_damage_taken: u32,
+    /// Kills while each wave was the latest one, in order of the waves starting
+    pub kills_per_wave:     Vec<u32>,
+    /// Score sampled every [`SCORE_SAMPLE_INTERVAL`] of survival
+    pub score_samples:      Vec<u32>,
+    /// Survival time the bullet was last fired at
+    fired_at:               Option<Duration>,
+    current_shot_hit:       bool,
+}
+
+impl RunStats {
+    /// Share of shots that hit an enemy, from `0.0` to `1.0`.
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            return 0.0;
+        }
+        self.shots_hit as f32 / self.shots_fired as f32
+    }
+
+    pub fn average_retrieval(&self) -> Duration {
+        if self.bullets_retrieved == 0 {
+            return Duration::ZERO;
+        }
+        self.total_retrieval / self.bullets_retrieved
+    }
+}
+
+fn reset_run_stats(mut stats: ResMut<RunStats>) {
+    *stats = RunStats::default();
+}
+
+fn track_shot(_: On<BulletFired>, survival_time: Res<SurvivalTimer>, mut stats: ResMut<RunStats>) {
+    stats.shots_fired += 1;
+    stats.fired_at = Some(survival_time.0.elapsed());
+    stats.current_shot_hit = false;
+}
+
+fn track_hit(_: On<BulletHit>, mut stats: ResMut<RunStats>) {
+    if !stats.current_shot_hit && stats.fired_at.is_some() {
+        stats.current_shot_hit = true;
+        stats.shots_hit += 1;
+    }
+}
+
+fn track_retrieval(
+    _: On<BulletCollected>,
+    survival_time: Res<SurvivalTimer>,
+    mut stats: ResMut<RunStats>,
+) {
+    if let Some(fired_at) = stats.fired_at.take() {
+        stats.bullets_retrieved += 1;
+        stats.total_retrieval += survival_time.0.elapsed().saturating_sub(fired_at);
+    }
+}
+
+fn track_damage_taken(
+    event: On<DamageDealt>,
+    mut stats: ResMut<RunStats>,
+    players: Query<(), With<Player>>,
+    enemies: Query<&EnemyVariant>,
+) {
+    if !players.contains(event.target) {
+        return;
+    }
+    let variant = match event.cause {
+        DamageCause::Shockwave => Some(EnemyVariant::Boss),
+        _ => event
+            .source
+            .and_then(|source| enemies.get(source).ok().copied()),
+    };
+    match variant {
+        Some(variant) => *stats.damage_taken.entry(variant).or_default() += event.amount,
+        None => stats.other_damage_taken += event.amount,
+    }
+}
+
+fn track_kill(event: On<EntityDied>, mut stats: ResMut<RunStats>, enemies: Query<(), With<Enemy>>) {
+    if event.cause.is_player_attack()
+        && enemies.contains(event.entity)
+        && let Some(kills) = stats.kills_per_wave.last_mut()
+    {
+        *kills += 1;
+    }
+}
+
+fn track_wave(_: On<WaveStarted>, mut stats: ResMut<RunStats>) {
+    stats.kills_per_wave.push(0);
+}
+
+fn sample_score(survival_time: Res<SurvivalTimer>, score: Res<Score>, mut stats: ResMut<RunStats>) {
+    let samples = (survival_time.0.elapsed().as_secs() / SCORE_SAMPLE_INTERVAL.as_secs()) as usize;
+    if stats.score_samples.len() <= samples {
+        stats.score_samples.push(score.0);
+    }
+}
+
+fn spawn_run_summary(
+    mut commands: Commands,
+    stats: Res<RunStats>,
+    score: Res<Score>,
+    survival_time: Res<SurvivalTimer>,
+) {
+    let mut rows = vec![
+        ("Score".to_string(), score.0.to_string()),
+        (
+            "Time survived".to_string(),
+            format_time(survival_time.0.elapsed()),
+        ),
+        (
+            "Accuracy".to_string(),
+            format!(
+                "{:.0}% ({}/{} shots)",
+                stats.accuracy() * 100.0,
+                stats.shots_hit,
+                stats.shots_fired
+            ),
+        ),
+        (
+            "Avg. bullet retrieval".to_string(),
+            format!("{:.1}s", stats.average_retrieval().as_secs_f32()),
+        ),
+    ];
+    rows.extend(EnemyVariant::ALL.iter().map(|variant| {
+        (
+            format!("Damage from {} enemies", variant.name().to_lowercase()),
+            stats
+                .damage_taken
+                .get(variant)
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+        )
+    }));
+    rows.push((
+        "Damage from hazards".to_string(),
+        stats.other_damage_taken.to_string(),
+    ));
+
+    let kills_per_wave = stats.kills_per_wave.clone();
+    let mut score_samples = stats.score_samples.clone();
+    score_samples.push(score.0);
+
+    commands.spawn((
+        widget::ui_root("Run Summary"),
+        GlobalZIndex(1),
+        DespawnOnExit(Screen::Gameplay),
+        BackgroundColor(Color::BLACK.with_alpha(0.6)),
+        children![
+            widget::h2("Game Over"),
+            (
+                Name::new("Run Stats Grid"),
+                widget::settings_grid_2x(),
+                Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                    for (name, value) in rows {
+                        parent.spawn((widget::label(name), widget::self_end()));
+                        parent.spawn((widget::label(value), widget::self_start()));
+                    }
+                })),
+            ),
+            widget::label("Kills per wave"),
+            bar_graph("Kills Per Wave Graph", kills_per_wave),
+            widget::label("Score over time"),
+            bar_graph("Score Graph", score_samples),
+        ],
+    ));
+}
+
+/// Bars of heights relative to the largest value, squeezed into a fixed width.
+fn bar_graph(name: &'static str, values: Vec<u32>) -> impl Bundle {
+    let max = values.iter().copied().max().unwrap_or_default().max(1);
+    let bar_width = GRAPH_WIDTH / values.len().max(1) as f32;
+    (
+        Name::new(name),
+        Node {
+            width: px(GRAPH_WIDTH),
+            height: px(GRAPH_HEIGHT),
+            align_items: AlignItems::FlexEnd,
+            border: UiRect::bottom(px(2)),
+            ..default()
+        },
+        BorderColor::all(LABEL_TEXT),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            for value in values {
+                parent.spawn((
+                    Name::new("Graph Bar"),
+                    Node {
+                        width: px(bar_width),
+                        height: percent(value as f32 / max as f32 * 100.0),
+                        margin: UiRect::horizontal(px((bar_width * 0.1).min(2.0))),
+                        ..default()
+                    },
+                    BackgroundColor(GRAPH_BAR),
+                ));
+            }
+        })),
+    )
+}
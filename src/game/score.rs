@@ -1,9 +1,14 @@
-use crate::{AppSystems, GameplaySet};
+use crate::{
+    AppSystems, GameplaySet,
+    game::{enemy::EnemySettings, health::EntityDied},
+};
 use bevy::prelude::*;
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<Score>();
 
+    app.add_observer(award_kill_score);
+
     app.add_systems(
         Update,
         render_score
@@ -26,3 +31,16 @@ fn render_score(score: Res<Score>, query: Query<&mut TextSpan, With<ScoreValueUi
         ui_text.0 = score.0.to_string();
     }
 }
+
+fn award_kill_score(
+    event: On<EntityDied>,
+    mut score: ResMut<Score>,
+    enemies: Query<&EnemySettings>,
+) {
+    if !event.cause.is_player_attack() {
+        return;
+    }
+    if let Ok(settings) = enemies.get(event.entity) {
+        score.0 += settings.score_worth;
+    }
+}
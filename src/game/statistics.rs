@@ -0,0 +1,202 @@
+//! Lifetime statistics across all runs, saved to a local file after every run.
+
+use crate::{
+    AppSystems, GameplaySet,
+    game::{
+        bullet::{BulletCollected, BulletFired},
+        enemy::EnemyVariant,
+        health::EntityDied,
+        player::Player,
+        survival_timer::SurvivalTimer,
+    },
+    game_state::GameOver,
+    screens::Screen,
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.insert_resource(Statistics::load())
+        .init_resource::<Combo>();
+
+    app.add_observer(count_kills)
+        .add_observer(count_deaths)
+        .add_observer(count_shots)
+        .add_observer(count_collected_bullets);
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_combo);
+    app.add_systems(OnEnter(GameOver(true)), save_statistics);
+    app.add_systems(OnExit(Screen::Gameplay), save_statistics);
+
+    app.add_systems(
+        Update,
+        track_longest_survival
+            .run_if(in_state(GameOver(false)))
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
+}
+
+/// Directory statistics, unlocked achievements, high scores and daily leaderboards are saved to,
+/// relative to the working directory.
+#[cfg(not(target_family = "wasm"))]
+pub const SAVE_DIR: &str = "save";
+#[cfg(not(target_family = "wasm"))]
+const STATISTICS_FILE: &str = "statistics.txt";
+
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+#[reflect(Resource)]
+pub struct Statistics {
+    /// Enemies killed by the player, per variant
+    pub kills:             HashMap<EnemyVariant, u32>,
+    pub shots_fired:       u32,
+    pub bullets_collected: u32,
+    pub longest_survival:  Duration,
+    /// Most enemies killed with a single shot, see [`Combo`]
+    pub max_combo:         u32,
+    pub deaths:            u32,
+}
+
+impl Statistics {
+    pub fn kills_of(&self, variant: EnemyVariant) -> u32 {
+        self.kills.get(&variant).copied().unwrap_or_default()
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    fn to_lines(&self) -> String {
+        let mut lines = EnemyVariant::ALL
+            .iter()
+            .map(|variant| format!("{} {}\n", kills_key(*variant), self.kills_of(*variant)))
+            .collect::<String>();
+        lines.push_str(&format!("shots_fired {}\n", self.shots_fired));
+        lines.push_str(&format!("bullets_collected {}\n", self.bullets_collected));
+        lines.push_str(&format!(
+            "longest_survival {}\n",
+            self.longest_survival.as_millis()
+        ));
+        lines.push_str(&format!("max_combo {}\n", self.max_combo));
+        lines.push_str(&format!("deaths {}\n", self.deaths));
+        lines
+    }
+
+    fn from_lines(content: &str) -> Self {
+        let mut stats = Self::default();
+        for (key, value) in content.lines().filter_map(|line| line.split_once(' ')) {
+            let Ok(value) = value.parse::<u64>() else {
+                continue;
+            };
+            let count = value.min(u32::MAX as u64) as u32;
+            match key {
+                "shots_fired" => stats.shots_fired = count,
+                "bullets_collected" => stats.bullets_collected = count,
+                "longest_survival" => stats.longest_survival = Duration::from_millis(value),
+                "max_combo" => stats.max_combo = count,
+                "deaths" => stats.deaths = count,
+                key => {
+                    if let Some(variant) = EnemyVariant::ALL
+                        .into_iter()
+                        .find(|variant| kills_key(*variant) == key)
+                    {
+                        stats.kills.insert(variant, count);
+                    }
+                },
+            }
+        }
+        stats
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn load() -> Self {
+        std::fs::read_to_string(std::path::Path::new(SAVE_DIR).join(STATISTICS_FILE))
+            .map(|content| Self::from_lines(&content))
+            .unwrap_or_default()
+    }
+
+    #[cfg(target_family = "wasm")]
+    fn load() -> Self {
+        Self::default()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn save(&self) -> std::io::Result<()> {
+        std::fs::create_dir_all(SAVE_DIR)?;
+        std::fs::write(
+            std::path::Path::new(SAVE_DIR).join(STATISTICS_FILE),
+            self.to_lines(),
+        )
+    }
+
+    /// No file system on the web, statistics only last for the session.
+    #[cfg(target_family = "wasm")]
+    fn save(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Enemies killed with the current shot, reset whenever the bullet is fired again.
+#[derive(Resource, Reflect, Clone, Copy, Default, Debug)]
+#[reflect(Resource)]
+pub struct Combo(pub u32);
+
+fn kills_key(variant: EnemyVariant) -> String {
+    format!("kills_{}", variant.name().to_lowercase())
+}
+
+fn reset_combo(mut combo: ResMut<Combo>) {
+    combo.0 = 0;
+}
+
+fn count_kills(
+    event: On<EntityDied>,
+    mut stats: ResMut<Statistics>,
+    mut combo: ResMut<Combo>,
+    enemies: Query<&EnemyVariant>,
+) {
+    if !event.cause.is_player_attack() {
+        return;
+    }
+    let Ok(variant) = enemies.get(event.entity) else {
+        return;
+    };
+    *stats.kills.entry(*variant).or_default() += 1;
+    combo.0 += 1;
+    if combo.0 > stats.max_combo {
+        stats.max_combo = combo.0;
+    }
+}
+
+fn count_deaths(
+    event: On<EntityDied>,
+    mut stats: ResMut<Statistics>,
+    players: Query<(), With<Player>>,
+) {
+    if players.contains(event.entity) {
+        stats.deaths += 1;
+    }
+}
+
+fn count_shots(_: On<BulletFired>, mut stats: ResMut<Statistics>, mut combo: ResMut<Combo>) {
+    stats.shots_fired += 1;
+    combo.0 = 0;
+}
+
+fn count_collected_bullets(_: On<BulletCollected>, mut stats: ResMut<Statistics>) {
+    stats.bullets_collected += 1;
+}
+
+/// Only updated once per second, so achievements aren't re-checked every frame.
+fn track_longest_survival(survival_time: Res<SurvivalTimer>, mut stats: ResMut<Statistics>) {
+    let elapsed = survival_time.0.elapsed();
+    if elapsed.as_secs() > stats.longest_survival.as_secs() {
+        stats.longest_survival = elapsed;
+    }
+}
+
+fn save_statistics(stats: Res<Statistics>) {
+    if let Err(err) = stats.save() {
+        warn!("Failed to save statistics: {err}");
+    }
+}
@@ -69,7 +69,7 @@ fn render_timer(timer: Res<SurvivalTimer>, query: Query<&mut TextSpan, With<Time
     }
 }
 
-fn format_time(time: Duration) -> String {
+pub fn format_time(time: Duration) -> String {
     let total_seconds = time.as_secs();
     let minutes = total_seconds / 60;
     let seconds = total_seconds % 60;
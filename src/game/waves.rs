@@ -1,26 +1,59 @@
 use crate::{
     AppSystems, GameplaySet,
     game::{
-        enemy::{EnemiesEnabled, Enemy, EnemyVariant},
+        boss::BossFight,
+        daily::RunDailyChallenge,
+        difficulty::{AdaptiveDifficulty, RunDifficulty},
+        enemy::{
+            Elite, EnemiesEnabled, Enemy, EnemyBudget, EnemyCount, EnemySpawnQueue, EnemyVariant,
+            QueuedEnemy,
+        },
+        health::{Dead, HealthRegen},
         player::Player,
+        run_rng::RunRng,
         score::Score,
         survival_timer::SurvivalTimer,
     },
     game_state::GameOver,
+    screens::Screen,
 };
-use bevy::{ecs::relationship::RelatedSpawner, prelude::*};
-use rand::Rng;
+use bevy::prelude::*;
+use rand::{Rng, RngCore};
+use std::{f32::consts::TAU, time::Duration};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
         Update,
-        handle_waves_manager
+        (
+            handle_waves_manager.run_if(in_state(BossFight(false))),
+            spawn_wave_groups,
+        )
+            .chain()
             .run_if(in_state(GameOver(false)).and(in_state(EnemiesEnabled(true))))
             .in_set(AppSystems::Update)
             .in_set(GameplaySet),
     );
+    app.add_systems(OnExit(BossFight(true)), skip_missed_waves);
+
+    app.add_observer(spawn_wave_banner);
+
+    app.add_systems(
+        Update,
+        update_wave_banners
+            .in_set(AppSystems::TickTimers)
+            .in_set(GameplaySet),
+    );
+    app.add_systems(
+        Update,
+        (render_next_wave, render_remaining_enemies)
+            .in_set(AppSystems::Update)
+            .in_set(GameplaySet),
+    );
 }
 
+const WAVE_BANNER_DURATION: Duration = Duration::from_millis(2500);
+const WAVE_BANNER_FADE: f32 = 0.3;
+
 #[derive(Bundle)]
 pub struct WavesManagerBundle {
     manager:  WavesManager,
@@ -40,12 +73,46 @@ pub fn waves_managers() -> Vec<WavesManagerBundle> {
     vec![
         WavesManagerBundle::from_settings(WavesManagerSettings::default()),
         WavesManagerBundle::from_settings(WavesManagerSettings {
-            enemy_variant:            EnemyVariant::Bigger,
-            spawn_every_n_secs:       60,
-            initial_enemies:          1,
-            enemies_incr_per_wave:    1,
-            enemy_spawn_radius_range: (200.0, 400.0),
-            score_mult:               500.0,
+            name:               "Bigger",
+            spawn_every_n_secs: 60,
+            groups:             vec![EnemyGroup {
+                enemy_variant: EnemyVariant::Bigger,
+                initial_enemies: 1,
+                enemies_incr_per_wave: 1,
+                radius_range: (200.0, 400.0),
+                ..default()
+            }],
+            score_mult:         500.0,
+            survival_regen:     10,
+        }),
+        WavesManagerBundle::from_settings(WavesManagerSettings {
+            name:               "Ambush",
+            spawn_every_n_secs: 45,
+            groups:             vec![
+                EnemyGroup {
+                    initial_enemies: 4,
+                    formation: Formation::Pincer,
+                    radius_range: (120.0, 160.0),
+                    ..default()
+                },
+                EnemyGroup {
+                    initial_enemies: 3,
+                    formation: Formation::Line,
+                    delay: Duration::from_secs(3),
+                    radius_range: (150.0, 200.0),
+                    ..default()
+                },
+                EnemyGroup {
+                    enemy_variant:         EnemyVariant::Bigger,
+                    initial_enemies:       1,
+                    enemies_incr_per_wave: 0,
+                    formation:             Formation::Cluster,
+                    delay:                 Duration::from_secs(6),
+                    radius_range:          (200.0, 250.0),
+                },
+            ],
+            score_mult:         50.0,
+            survival_regen:     5,
         }),
     ]
 }
@@ -53,29 +120,116 @@ pub fn waves_managers() -> Vec<WavesManagerBundle> {
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
 struct WavesManagerSettings {
-    /// Enemy variant this waves manager spawns
-    enemy_variant:            EnemyVariant,
+    /// Shown in the next wave countdown
+    name:               &'static str,
     /// Spawn a new wave every N seconds of the survival timer
-    spawn_every_n_secs:       u32,
+    spawn_every_n_secs: u32,
+    /// Enemy groups making up each wave
+    groups:             Vec<EnemyGroup>,
+    /// Add (wave_index * score_mult) on new wave
+    score_mult:         f32,
+    /// Health the player regenerates (with overheal) for surviving the previous wave
+    survival_regen:     u32,
+}
+
+impl Default for WavesManagerSettings {
+    fn default() -> Self {
+        Self {
+            name:               "Basic",
+            spawn_every_n_secs: 10,
+            groups:             vec![EnemyGroup::default()],
+            score_mult:         1.0,
+            survival_regen:     2,
+        }
+    }
+}
+
+/// A group of enemies of the same variant spawned together within a wave.
+#[derive(Reflect, Clone, Debug)]
+struct EnemyGroup {
+    enemy_variant:         EnemyVariant,
     /// Base amount of enemies to spawn each wave
-    initial_enemies:          u32,
+    initial_enemies:       u32,
     /// Spawns additional (wave_index * N) enemies each wave
-    enemies_incr_per_wave:    u32,
+    enemies_incr_per_wave: u32,
+    formation:             Formation,
+    /// Time after the wave started before this group spawns
+    delay:                 Duration,
     /// Distance to player to spawn enemies at, randomized in this range
-    enemy_spawn_radius_range: (f32, f32),
-    /// Add (wave_index * score_mult) on new wave
-    score_mult:               f32,
+    radius_range:          (f32, f32),
 }
 
-impl Default for WavesManagerSettings {
+impl Default for EnemyGroup {
     fn default() -> Self {
         Self {
-            enemy_variant:            EnemyVariant::Basic,
-            spawn_every_n_secs:       10,
-            initial_enemies:          3,
-            enemies_incr_per_wave:    1,
-            enemy_spawn_radius_range: (100.0, 300.0),
-            score_mult:               1.0,
+            enemy_variant:         EnemyVariant::Basic,
+            initial_enemies:       3,
+            enemies_incr_per_wave: 1,
+            formation:             Formation::Ring,
+            delay:                 Duration::ZERO,
+            radius_range:          (100.0, 300.0),
+        }
+    }
+}
+
+impl EnemyGroup {
+    /// Amount of enemies in this group, `wave_size` multiplies it from the run's difficulty.
+    fn enemy_count(&self, wave_index: u32, wave_size: f32) -> u32 {
+        let enemies_to_spawn = self.initial_enemies + (wave_index * self.enemies_incr_per_wave);
+        ((enemies_to_spawn as f32 * wave_size).round() as u32).max(1)
+    }
+}
+
+/// How the enemies of an [`EnemyGroup`] are arranged around the player.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum Formation {
+    /// Evenly spread in a ring around the player
+    #[default]
+    Ring,
+    /// A straight wall on one side of the player
+    Line,
+    /// Bunched up on one side of the player
+    Cluster,
+    /// Two clusters closing in from opposite sides
+    Pincer,
+}
+
+impl Formation {
+    const LINE_SPACING: f32 = 18.0;
+    const CLUSTER_RADIUS: f32 = 30.0;
+
+    /// Offsets from the wave's origin for `count` enemies, at distances within `radius_range`.
+    fn offsets(&self, count: u32, radius_range: (f32, f32), rng: &mut impl Rng) -> Vec<Vec2> {
+        let direction = Vec2::from_angle(rng.random_range(0.0 .. TAU));
+        let distance = |rng: &mut dyn RngCore| rng.random_range(radius_range.0 ..= radius_range.1);
+        let cluster = |rng: &mut dyn RngCore, center: Vec2| {
+            let angle = rng.random_range(0.0 .. TAU);
+            center + Vec2::from_angle(angle) * rng.random_range(0.0 .. Self::CLUSTER_RADIUS)
+        };
+
+        match self {
+            Self::Ring => (0 .. count)
+                .map(|index| Vec2::from_angle(index as f32 / count as f32 * TAU) * distance(rng))
+                .collect(),
+            Self::Line => {
+                let center = direction * distance(rng);
+                (0 .. count)
+                    .map(|index| {
+                        let along = index as f32 - (count - 1) as f32 * 0.5;
+                        center + direction.perp() * along * Self::LINE_SPACING
+                    })
+                    .collect()
+            },
+            Self::Cluster => {
+                let center = direction * distance(rng);
+                (0 .. count).map(|_| cluster(rng, center)).collect()
+            },
+            Self::Pincer => {
+                let centers = [direction * distance(rng), -direction * distance(rng)];
+                (0 .. count)
+                    .map(|index| cluster(rng, centers[index as usize % 2]))
+                    .collect()
+            },
         }
     }
 }
@@ -101,15 +255,55 @@ pub struct Wave;
 #[reflect(Component)]
 struct WaveCounter(u32);
 
+/// An [`EnemyGroup`] of a wave waiting for its delay before spawning its enemies.
+#[derive(Component)]
+#[require(Name::new("Wave Group"))]
+struct PendingGroup {
+    group:       EnemyGroup,
+    group_index: usize,
+    wave_index:  u32,
+    enemy_count: u32,
+    delay:       Timer,
+}
+
+/// Triggered on a [`WavesManager`] when it spawns a new wave.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct WaveStarted {
+    #[event_target]
+    pub manager:     Entity,
+    /// Index of the wave within its manager, starting at 0
+    pub wave_index:  u32,
+    /// Total enemies of all groups in the wave
+    pub enemy_count: u32,
+}
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct NextWaveValueUi;
+
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct RemainingEnemiesValueUi;
+
+/// "Wave N" text shown in the center of the screen for a moment when a wave starts.
+#[derive(Component)]
+struct WaveBanner(Timer);
+
 fn handle_waves_manager(
     mut commands: Commands,
     survival_time: Res<SurvivalTimer>,
+    difficulty: Res<RunDifficulty>,
+    adaptive: Res<AdaptiveDifficulty>,
     mut score: ResMut<Score>,
     mut wave_managers: Query<(Entity, &WavesManagerSettings, &mut WaveCounter), With<WavesManager>>,
-    players: Query<&Transform, With<Player>>,
+    mut players: Query<(Entity, &Transform, Option<&mut HealthRegen>), With<Player>>,
 ) {
-    for player_transform in players {
+    let wave_size = difficulty.wave_size(&adaptive);
+
+    for (player, player_transform, mut regen) in &mut players {
         let player_pos = player_transform.translation.truncate();
+        // Summed over all waves starting this frame, the inserted regen would overwrite itself
+        let mut regen_amount = 0;
 
         for (manager_entity, settings, mut wave_counter) in &mut wave_managers {
             let time_s = survival_time.0.elapsed().as_secs() as u32;
@@ -121,48 +315,277 @@ fn handle_waves_manager(
             }
 
             for _ in 0 .. waves_to_spawn {
+                if wave_counter.0 > 0 {
+                    regen_amount += settings.survival_regen;
+                }
+                let enemy_count = settings
+                    .groups
+                    .iter()
+                    .map(|group| group.enemy_count(wave_counter.0, wave_size))
+                    .sum();
                 commands.entity(manager_entity).with_child(wave(
-                    settings.clone(),
+                    settings,
                     wave_counter.0,
+                    wave_size,
                     player_pos,
                 ));
+                commands.trigger(WaveStarted {
+                    manager: manager_entity,
+                    wave_index: wave_counter.0,
+                    enemy_count,
+                });
                 wave_counter.0 += 1;
                 score.0 += (wave_counter.0 as f32 * settings.score_mult) as u32;
             }
         }
+
+        if regen_amount > 0 {
+            survival_regen(&mut commands, player, regen.as_deref_mut(), regen_amount);
+        }
+    }
+}
+
+/// Waves are paused during boss fights, don't spawn all the missed waves at once afterwards.
+fn skip_missed_waves(
+    survival_time: Res<SurvivalTimer>,
+    wave_managers: Query<(&WavesManagerSettings, &mut WaveCounter), With<WavesManager>>,
+) {
+    let time_s = survival_time.0.elapsed().as_secs() as u32;
+    for (settings, mut wave_counter) in wave_managers {
+        wave_counter.0 = wave_counter.0.max(time_s / settings.spawn_every_n_secs);
     }
 }
 
-fn wave(settings: WavesManagerSettings, wave_index: u32, player_pos: Vec2) -> impl Bundle {
-    // let enemies_to_spawn = wave_index * assets.enemies_incr_per_wave
-    //     + if wave_index == 0 { assets.initial_enemies
-    //     } else {
-    //         0
-    //     };
+/// Regenerate the player's health for surviving waves,
+/// stacking onto any regeneration that is still running.
+fn survival_regen(
+    commands: &mut Commands,
+    player: Entity,
+    regen: Option<&mut HealthRegen>,
+    amount: u32,
+) {
+    const REGEN_INTERVAL: Duration = Duration::from_millis(250);
+
+    match regen {
+        Some(HealthRegen {
+            remaining: Some(remaining),
+            ..
+        }) => *remaining += amount,
+        // Keep an indefinite regen's own rate, add the burst on top
+        Some(regen) => regen.bonus += amount,
+        None => {
+            commands
+                .entity(player)
+                .insert(HealthRegen::burst(amount, 1, REGEN_INTERVAL).with_overheal());
+        },
+    }
+}
 
-    let enemies_to_spawn = settings.initial_enemies + (wave_index * settings.enemies_incr_per_wave);
+fn wave(
+    settings: &WavesManagerSettings,
+    wave_index: u32,
+    wave_size: f32,
+    player_pos: Vec2,
+) -> impl Bundle {
+    let groups = settings
+        .groups
+        .iter()
+        .enumerate()
+        .map(|(group_index, group)| PendingGroup {
+            group: group.clone(),
+            group_index,
+            wave_index,
+            enemy_count: group.enemy_count(wave_index, wave_size),
+            delay: Timer::new(group.delay, TimerMode::Once),
+        })
+        .collect::<Vec<_>>();
 
     (
         Wave,
         Name::new(format!("Wave {}", wave_index)),
         Transform::from_translation(player_pos.extend(0.0)),
-        Children::spawn(SpawnWith(move |parent: &mut RelatedSpawner<ChildOf>| {
-            for enemy_index in 0 .. enemies_to_spawn {
-                let distance = rand::rng().random_range(
-                    settings.enemy_spawn_radius_range.0 ..= settings.enemy_spawn_radius_range.1,
-                );
+        Children::spawn(SpawnIter(groups.into_iter())),
+    )
+}
+
+/// Spawn the enemies of each wave group once its delay finished, relative to the wave's origin.
+/// Enemies exceeding the [`EnemyBudget`] are queued instead.
+fn spawn_wave_groups(
+    mut commands: Commands,
+    time: Res<Time>,
+    budget: Res<EnemyBudget>,
+    daily: Res<RunDailyChallenge>,
+    mut rng: ResMut<RunRng>,
+    mut count: ResMut<EnemyCount>,
+    mut queue: ResMut<EnemySpawnQueue>,
+    groups: Query<(Entity, &mut PendingGroup, &ChildOf)>,
+    waves: Query<(&Transform, &ChildOf), With<Wave>>,
+    managers: Query<&GlobalTransform, With<WavesManager>>,
+) {
+    for (entity, mut pending, child_of) in groups {
+        pending.delay.tick(time.delta());
+        if !pending.delay.is_finished() {
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        let group = &pending.group;
+        let enemy_variant = match &daily.0 {
+            Some(daily) => daily.enemy_variant(group.enemy_variant),
+            None => group.enemy_variant,
+        };
+        let offsets =
+            group
+                .formation
+                .offsets(pending.enemy_count, group.radius_range, &mut rng.spawns);
 
-                let angle = (enemy_index as f32 / enemies_to_spawn as f32) * std::f32::consts::TAU;
-                let offset = Vec2::new(angle.cos(), angle.sin()) * distance;
-                let transform = Transform::from_translation(offset.extend(0.0));
+        let wave = child_of.parent();
+        // A new wave's `GlobalTransform` isn't propagated yet, go through its manager instead
+        let origin = waves
+            .get(wave)
+            .map(|(transform, child_of)| {
+                managers
+                    .get(child_of.parent())
+                    .map(|manager| manager.transform_point(transform.translation))
+                    .unwrap_or(transform.translation)
+                    .truncate()
+            })
+            .unwrap_or_default();
 
-                parent.spawn((
+        commands.entity(wave).with_children(|parent| {
+            for (enemy_index, offset) in offsets.into_iter().enumerate() {
+                let elite = Elite::roll(pending.wave_index, &mut rng.spawns);
+                if !count.try_reserve(&budget) {
+                    queue.push(QueuedEnemy {
+                        variant: enemy_variant,
+                        position: origin + offset,
+                        elite,
+                    });
+                    continue;
+                }
+
+                let mut enemy = parent.spawn((
                     Enemy,
-                    settings.enemy_variant,
-                    Name::new(format!("Enemy W{}-I{}", wave_index, enemy_index)),
-                    transform,
+                    enemy_variant,
+                    Name::new(format!(
+                        "Enemy W{}-G{}-I{}",
+                        pending.wave_index, pending.group_index, enemy_index
+                    )),
+                    Transform::from_translation(offset.extend(0.0)),
                 ));
+                if let Some(elite) = elite {
+                    enemy.insert(elite);
+                }
             }
-        })),
-    )
+        });
+    }
+}
+
+fn spawn_wave_banner(
+    event: On<WaveStarted>,
+    mut commands: Commands,
+    banners: Query<Entity, With<WaveBanner>>,
+) {
+    // Managers may start waves at the same time, only show the latest
+    for banner in banners {
+        commands.entity(banner).despawn();
+    }
+
+    commands.spawn((
+        Name::new("Wave Banner"),
+        WaveBanner(Timer::new(WAVE_BANNER_DURATION, TimerMode::Once)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: percent(25),
+            width: percent(100),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: px(4),
+            ..default()
+        },
+        DespawnOnExit(Screen::Gameplay),
+        Pickable::IGNORE,
+        children![
+            (
+                Name::new("Wave Banner Title"),
+                Text::new(format!("Wave {}", event.wave_index + 1)),
+                TextFont::from_font_size(48.0),
+                TextColor(Color::WHITE),
+            ),
+            (
+                Name::new("Wave Banner Enemies"),
+                Text::new(format!("{} enemies", event.enemy_count)),
+                TextFont::from_font_size(24.0),
+                TextColor(Color::WHITE),
+            ),
+        ],
+    ));
+}
+
+fn update_wave_banners(
+    mut commands: Commands,
+    time: Res<Time>,
+    banners: Query<(Entity, &mut WaveBanner, &Children)>,
+    mut texts: Query<&mut TextColor>,
+) {
+    for (entity, mut banner, children) in banners {
+        banner.0.tick(time.delta());
+        if banner.0.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Fade out at the end
+        let alpha = (banner.0.remaining_secs() / WAVE_BANNER_FADE).min(1.0);
+        for child in children {
+            if let Ok(mut color) = texts.get_mut(*child) {
+                color.0.set_alpha(alpha);
+            }
+        }
+    }
+}
+
+/// Countdown to the next wave of every manager.
+fn render_next_wave(
+    survival_time: Res<SurvivalTimer>,
+    boss_fight: Res<State<BossFight>>,
+    wave_managers: Query<(&WavesManagerSettings, &WaveCounter), With<WavesManager>>,
+    query: Query<&mut TextSpan, With<NextWaveValueUi>>,
+) {
+    let text = if boss_fight.get().0 {
+        "after the boss".to_string()
+    } else {
+        let time_s = survival_time.0.elapsed_secs();
+        wave_managers
+            .iter()
+            .map(|(settings, wave_counter)| {
+                let next_wave_s = ((wave_counter.0 + 1) * settings.spawn_every_n_secs) as f32;
+                let remaining = (next_wave_s - time_s).max(0.0).ceil();
+                format!("{} {}s", settings.name, remaining)
+            })
+            .collect::<Vec<_>>()
+            .join(" / ")
+    };
+
+    for mut ui_text in query {
+        if ui_text.0 != text {
+            ui_text.0.clone_from(&text);
+        }
+    }
+}
+
+fn render_remaining_enemies(
+    queue: Res<EnemySpawnQueue>,
+    enemies: Query<(), (With<Enemy>, Without<Dead>)>,
+    query: Query<&mut TextSpan, With<RemainingEnemiesValueUi>>,
+) {
+    let text = match queue.len() {
+        0 => enemies.iter().count().to_string(),
+        queued => format!("{} (+{} queued)", enemies.iter().count(), queued),
+    };
+    for mut ui_text in query {
+        if ui_text.0 != text {
+            ui_text.0.clone_from(&text);
+        }
+    }
 }
@@ -15,6 +15,11 @@ pub enum PlayerAction {
     Aim,
     #[actionlike(Button)]
     Shoot,
+    /// Pull the fired bullet back
+    #[actionlike(Button)]
+    Recall,
+    #[actionlike(Button)]
+    Dash,
 }
 
 impl PlayerAction {
@@ -33,5 +38,10 @@ impl PlayerAction {
             .with(Shoot, GamepadButton::RightTrigger2)
             .with(Shoot, GamepadButton::LeftTrigger)
             .with(Shoot, GamepadButton::LeftTrigger2)
+            // Not R, the debug input map binds Ctrl+R
+            .with(Recall, KeyCode::KeyC)
+            .with(Recall, GamepadButton::RightThumb)
+            .with(Dash, KeyCode::ShiftLeft)
+            .with(Dash, GamepadButton::LeftThumb)
     }
 }
@@ -0,0 +1,99 @@
+//! Achievements and lifetime statistics, opened from the main menu.
+
+use crate::{
+    game::{
+        achievements::{Achievement, UnlockedAchievements},
+        enemy::EnemyVariant,
+        statistics::Statistics,
+        survival_timer::format_time,
+    },
+    menus::{Menu, pop_menu_on_click},
+    theme::{palette::LABEL_TEXT, widget},
+};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Achievements), spawn_achievements_menu);
+}
+
+/// Text color of achievements that are still locked.
+const LOCKED_TEXT: Color = Color::srgb(0.45, 0.45, 0.45);
+
+fn spawn_achievements_menu(
+    mut commands: Commands,
+    stats: Res<Statistics>,
+    unlocked: Res<UnlockedAchievements>,
+) {
+    let achievements =
+        Achievement::ALL.map(|achievement| (achievement, unlocked.contains(achievement)));
+    let unlocked_count = unlocked.count();
+    let stats = stats.clone();
+
+    commands.spawn((
+        widget::ui_root("Achievements Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Achievements),
+        children![
+            widget::h2(format!(
+                "Achievements {unlocked_count}/{}",
+                Achievement::ALL.len()
+            )),
+            (
+                Name::new("Achievements Grid"),
+                widget::settings_grid_2x(),
+                Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                    for (achievement, is_unlocked) in achievements {
+                        let color = if is_unlocked { LABEL_TEXT } else { LOCKED_TEXT };
+                        parent
+                            .spawn((widget::label(achievement.name()), widget::self_end()))
+                            .insert(TextColor(color));
+                        parent
+                            .spawn((
+                                widget::label(achievement.description()),
+                                widget::self_start(),
+                            ))
+                            .insert(TextColor(color));
+                    }
+                })),
+            ),
+            widget::h2("Statistics"),
+            (
+                Name::new("Statistics Grid"),
+                widget::settings_grid_2x(),
+                Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                    for (name, value) in statistic_rows(&stats) {
+                        parent.spawn((widget::label(name), widget::self_end()));
+                        parent.spawn((widget::label(value), widget::self_start()));
+                    }
+                })),
+            ),
+            widget::button("Back", pop_menu_on_click),
+        ],
+    ));
+}
+
+fn statistic_rows(stats: &Statistics) -> Vec<(String, String)> {
+    let mut rows = EnemyVariant::ALL
+        .iter()
+        .map(|variant| {
+            (
+                format!("{} enemies killed", variant.name()),
+                stats.kills_of(*variant).to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    rows.extend([
+        ("Shots fired".to_string(), stats.shots_fired.to_string()),
+        (
+            "Bullets collected".to_string(),
+            stats.bullets_collected.to_string(),
+        ),
+        (
+            "Longest survival".to_string(),
+            format_time(stats.longest_survival),
+        ),
+        ("Max combo".to_string(), stats.max_combo.to_string()),
+        ("Deaths".to_string(), stats.deaths.to_string()),
+    ]);
+    rows
+}
@@ -0,0 +1,68 @@
+//! Daily challenge menu, opened from the main menu.
+
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::daily::{DailyChallenge, StartDailyChallenge, load_leaderboard},
+    menus::{Menu, pop_menu_on_click},
+    screens::Screen,
+    theme::widget,
+};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::DailyChallenge), spawn_daily_challenge_menu);
+}
+
+/// Amount of today's results listed in the menu.
+const SHOWN_RESULTS: usize = 5;
+
+fn spawn_daily_challenge_menu(mut commands: Commands) {
+    let challenge = DailyChallenge::today();
+    let leaderboard = load_leaderboard(&challenge.date);
+
+    commands.spawn((
+        widget::ui_root("Daily Challenge Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::DailyChallenge),
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            parent.spawn(widget::h2(format!("Daily Challenge {}", challenge.date)));
+            parent.spawn(widget::label(format!(
+                "Bullet: {}",
+                challenge.bullet_type.name()
+            )));
+            for modifier in &challenge.modifiers {
+                parent.spawn(widget::label(modifier.name()));
+            }
+
+            parent.spawn(widget::h2("Today's best"));
+            if leaderboard.is_empty() {
+                parent.spawn(widget::label("No results yet"));
+            }
+            for (rank, result) in leaderboard.iter().take(SHOWN_RESULTS).enumerate() {
+                parent.spawn(widget::label(format!(
+                    "{}. {} ({}s)",
+                    rank + 1,
+                    result.score,
+                    result.survived.as_secs()
+                )));
+            }
+
+            parent.spawn(widget::button("Start", start_daily_challenge));
+            parent.spawn(widget::button("Back", pop_menu_on_click));
+        })),
+    ));
+}
+
+fn start_daily_challenge(
+    _: On<Pointer<Click>>,
+    mut commands: Commands,
+    resource_handles: Res<ResourceHandles>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    commands.trigger(StartDailyChallenge);
+    if resource_handles.is_all_done() {
+        next_screen.set(Screen::Gameplay);
+    } else {
+        next_screen.set(Screen::Loading);
+    }
+}
@@ -1,6 +1,6 @@
 //! The main menu (seen on the title screen).
 
-use crate::{asset_tracking::ResourceHandles, menus::Menu, screens::Screen, theme::widget};
+use crate::{menus::Menu, theme::widget};
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
@@ -14,7 +14,11 @@ fn spawn_main_menu(mut commands: Commands) {
         DespawnOnExit(Menu::Main),
         Children::spawn(SpawnWith(|parent: &mut ChildSpawner| {
             parent.spawn(widget::h1("Having Fun Yet?"));
-            parent.spawn(widget::button("Play", enter_loading_or_gameplay_screen));
+            parent.spawn(widget::button("Play", open_run_setup_menu));
+            // The date comes from the system clock, which isn't available on the web
+            #[cfg(not(target_family = "wasm"))]
+            parent.spawn(widget::button("Daily Challenge", open_daily_challenge_menu));
+            parent.spawn(widget::button("Achievements", open_achievements_menu));
             parent.spawn(widget::button("Settings", open_settings_menu));
             #[cfg(not(target_family = "wasm"))]
             parent.spawn(widget::button("Exit", exit_app));
@@ -22,16 +26,17 @@ fn spawn_main_menu(mut commands: Commands) {
     ));
 }
 
-fn enter_loading_or_gameplay_screen(
-    _: On<Pointer<Click>>,
-    resource_handles: Res<ResourceHandles>,
-    mut next_screen: ResMut<NextState<Screen>>,
-) {
-    if resource_handles.is_all_done() {
-        next_screen.set(Screen::Gameplay);
-    } else {
-        next_screen.set(Screen::Loading);
-    }
+fn open_run_setup_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::RunSetup);
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn open_daily_challenge_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::DailyChallenge);
+}
+
+fn open_achievements_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Achievements);
 }
 
 fn open_settings_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
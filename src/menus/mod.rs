@@ -3,19 +3,35 @@ use crate::{
     state_history::{InitStateHistory, StateHistory},
 };
 
+mod achievements;
+mod daily_challenge;
 mod main;
 mod pause;
+mod run_setup;
 mod settings;
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
     app.init_state_with_history::<Menu>();
-    app.add_plugins((main::plugin, settings::plugin, pause::plugin));
+    app.add_plugins((
+        main::plugin,
+        achievements::plugin,
+        daily_challenge::plugin,
+        run_setup::plugin,
+        settings::plugin,
+        pause::plugin,
+    ));
 
     app.add_systems(
         Update,
-        pop_menu.run_if(in_settings_state.and(action_just_pressed(MenuAction::Cancel))),
+        pop_menu.run_if(
+            in_settings_state
+                .or(in_state(Menu::RunSetup))
+                .or(in_state(Menu::DailyChallenge))
+                .or(in_state(Menu::Achievements))
+                .and(action_just_pressed(MenuAction::Cancel)),
+        ),
     );
 }
 
@@ -30,8 +46,12 @@ pub enum Menu {
     None,
     Pop,
     Main,
+    RunSetup,
+    DailyChallenge,
+    Achievements,
     Pause,
     Settings,
+    GameplaySettings,
     AudioSettings,
     VideoSettings,
 }
@@ -39,7 +59,10 @@ pub enum Menu {
 impl Menu {
     fn is_settings(&self) -> bool {
         use Menu::*;
-        matches!(self, Settings | AudioSettings | VideoSettings)
+        matches!(
+            self,
+            Settings | GameplaySettings | AudioSettings | VideoSettings
+        )
     }
 }
 
@@ -0,0 +1,200 @@
+//! Run setup menu, opened from the main menu before starting a run.
+
+use crate::{
+    asset_tracking::ResourceHandles,
+    game::{
+        bullet::{BulletType, RunBulletType},
+        difficulty::RunDifficulty,
+        game_mode::{HighScores, RunGameMode},
+        mutators::{Mutator, Mutators, RunMutators},
+    },
+    menus::{Menu, pop_menu_on_click},
+    screens::Screen,
+    theme::widget::{self, ValueChange, self_start, settings_list},
+};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::RunSetup), spawn_run_setup_menu);
+
+    app.add_systems(
+        Update,
+        (update_game_mode_labels, update_difficulty_label).run_if(in_state(Menu::RunSetup)),
+    );
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct GameModeLabel;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct HighScoreLabel;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct DifficultyLabel;
+
+#[derive(Component, Default)]
+struct AdaptiveDifficultyCheckbox;
+
+#[derive(Component)]
+struct MutatorCheckbox;
+
+fn spawn_run_setup_menu(
+    mut commands: Commands,
+    difficulty: Res<RunDifficulty>,
+    mutators: Res<RunMutators>,
+) {
+    commands.spawn((
+        widget::ui_root("Run Setup Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::RunSetup),
+        children![
+            widget::h2("Choose your mode"),
+            game_mode_grid(),
+            widget::h2("Choose your difficulty"),
+            difficulty_grid(difficulty.adaptive),
+            widget::h2("Mutators"),
+            mutator_grid(mutators.0),
+            widget::h2("Choose your bullet"),
+            bullet_type_grid(),
+            widget::label("Bigger enemies may drop other bullet types"),
+            widget::button("Back", pop_menu_on_click),
+        ],
+    ));
+}
+
+fn game_mode_grid() -> impl Bundle {
+    (settings_list(), children![
+        (Name::new("Game Mode Widget"), self_start(), children![
+            widget::label("Mode"),
+            widget::analog_slider(GameModeLabel, previous_game_mode, next_game_mode),
+        ]),
+        (Name::new("High Score Widget"), self_start(), children![(
+            widget::label(""),
+            HighScoreLabel
+        )]),
+    ])
+}
+
+fn previous_game_mode(_: On<Pointer<Click>>, mut mode: ResMut<RunGameMode>) {
+    mode.0 = mode.0.prev();
+}
+
+fn next_game_mode(_: On<Pointer<Click>>, mut mode: ResMut<RunGameMode>) {
+    mode.0 = mode.0.next();
+}
+
+fn update_game_mode_labels(
+    mode: Res<RunGameMode>,
+    mutators: Res<RunMutators>,
+    high_scores: Res<HighScores>,
+    mut mode_label: Single<&mut Text, (With<GameModeLabel>, Without<HighScoreLabel>)>,
+    mut high_score_label: Single<&mut Text, (With<HighScoreLabel>, Without<GameModeLabel>)>,
+) {
+    mode_label.0 = mode.0.name().to_string();
+    high_score_label.0 = match high_scores.best(mode.0, mutators.0) {
+        Some(best) => format!("Best: {best}"),
+        None => "No high score yet".to_string(),
+    };
+}
+
+fn difficulty_grid(is_adaptive: bool) -> impl Bundle {
+    (settings_list(), children![
+        (Name::new("Difficulty Widget"), self_start(), children![
+            widget::label("Difficulty"),
+            widget::analog_slider(DifficultyLabel, lower_difficulty, raise_difficulty),
+        ]),
+        (
+            Name::new("Adaptive Difficulty Toggle"),
+            self_start(),
+            children![(widget::checkbox(
+                AdaptiveDifficultyCheckbox,
+                "Adaptive wave sizes? ",
+                is_adaptive,
+                |trigger: On<ValueChange<bool>>, mut difficulty: ResMut<RunDifficulty>| {
+                    difficulty.adaptive = trigger.value;
+                }
+            ),),]
+        ),
+    ])
+}
+
+fn lower_difficulty(_: On<Pointer<Click>>, mut difficulty: ResMut<RunDifficulty>) {
+    difficulty.preset = difficulty.preset.prev();
+}
+
+fn raise_difficulty(_: On<Pointer<Click>>, mut difficulty: ResMut<RunDifficulty>) {
+    difficulty.preset = difficulty.preset.next();
+}
+
+fn update_difficulty_label(
+    difficulty: Res<RunDifficulty>,
+    mut label: Single<&mut Text, With<DifficultyLabel>>,
+) {
+    label.0 = difficulty.preset.name().to_string();
+}
+
+fn mutator_grid(mutators: Mutators) -> impl Bundle {
+    (
+        Name::new("Mutator Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(8),
+            column_gap: px(16),
+            grid_template_columns: RepeatedGridTrack::auto(2),
+            ..default()
+        },
+        Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+            for mutator in Mutator::ALL {
+                parent.spawn((self_start(), children![widget::checkbox(
+                    MutatorCheckbox,
+                    format!("{}? ", mutator.name()),
+                    mutators.contains(mutator),
+                    move |trigger: On<ValueChange<bool>>, mut mutators: ResMut<RunMutators>| {
+                        mutators.0.set(mutator, trigger.value);
+                    }
+                )]));
+            }
+        })),
+    )
+}
+
+fn bullet_type_grid() -> impl Bundle {
+    (
+        Name::new("Bullet Type Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(16),
+            column_gap: px(16),
+            grid_template_columns: RepeatedGridTrack::auto(2),
+            ..default()
+        },
+        Children::spawn(SpawnWith(|parent: &mut ChildSpawner| {
+            for bullet_type in BulletType::ALL {
+                parent.spawn(widget::button(
+                    bullet_type.name(),
+                    start_run_with_bullet_type(bullet_type),
+                ));
+            }
+        })),
+    )
+}
+
+fn start_run_with_bullet_type(
+    bullet_type: BulletType,
+) -> impl FnMut(On<Pointer<Click>>, ResMut<RunBulletType>, Res<ResourceHandles>, ResMut<NextState<Screen>>)
+{
+    move |_: On<Pointer<Click>>,
+          mut run_bullet_type: ResMut<RunBulletType>,
+          resource_handles: Res<ResourceHandles>,
+          mut next_screen: ResMut<NextState<Screen>>| {
+        run_bullet_type.0 = bullet_type;
+        if resource_handles.is_all_done() {
+            next_screen.set(Screen::Gameplay);
+        } else {
+            next_screen.set(Screen::Loading);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    game::bullet::{ChargeShotEnabled, RecallSettings, TrajectoryPreviewEnabled},
+    menus::{Menu, pop_menu_on_click},
+    theme::widget::{self, ValueChange, self_start, settings_list},
+};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(Menu::GameplaySettings),
+        spawn_gameplay_settings_menu,
+    );
+
+    app.add_systems(
+        Update,
+        update_recall_speed_label.run_if(in_state(Menu::GameplaySettings)),
+    );
+}
+
+#[derive(Component, Default)]
+struct TrajectoryPreviewCheckbox;
+
+#[derive(Component, Default)]
+struct ChargeShotCheckbox;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct RecallSpeedLabel;
+
+const MIN_RECALL_SPEED: f32 = 100.0;
+const MAX_RECALL_SPEED: f32 = 500.0;
+const RECALL_SPEED_STEP: f32 = 20.0;
+
+fn spawn_gameplay_settings_menu(
+    mut commands: Commands,
+    trajectory_preview: Res<TrajectoryPreviewEnabled>,
+    charge_shot: Res<ChargeShotEnabled>,
+) {
+    commands.spawn((
+        widget::ui_root("Gameplay Settings Menu"),
+        GlobalZIndex(4),
+        DespawnOnExit(Menu::GameplaySettings),
+        children![
+            widget::h2("Gameplay Settings"),
+            gameplay_settings_grid(trajectory_preview.0, charge_shot.0),
+            widget::button("Back", pop_menu_on_click),
+        ],
+    ));
+}
+
+fn gameplay_settings_grid(has_trajectory_preview: bool, has_charge_shot: bool) -> impl Bundle {
+    (settings_list(), children![
+        trajectory_preview_widget(has_trajectory_preview),
+        charge_shot_widget(has_charge_shot),
+        recall_speed_widget(),
+    ])
+}
+
+fn trajectory_preview_widget(has_trajectory_preview: bool) -> impl Bundle {
+    (
+        Name::new("Trajectory Preview Toggle"),
+        self_start(),
+        children![(widget::checkbox(
+            TrajectoryPreviewCheckbox,
+            "Aim trajectory preview? ",
+            has_trajectory_preview,
+            |trigger: On<ValueChange<bool>>, mut preview: ResMut<TrajectoryPreviewEnabled>| {
+                preview.0 = trigger.value;
+            }
+        ),),],
+    )
+}
+
+fn charge_shot_widget(has_charge_shot: bool) -> impl Bundle {
+    (Name::new("Charge Shot Toggle"), self_start(), children![(
+        widget::checkbox(
+            ChargeShotCheckbox,
+            "Hold to charge shots? ",
+            has_charge_shot,
+            |trigger: On<ValueChange<bool>>, mut charge_shot: ResMut<ChargeShotEnabled>| {
+                charge_shot.0 = trigger.value;
+            }
+        ),
+    ),])
+}
+
+fn recall_speed_widget() -> impl Bundle {
+    (Name::new("Recall Speed Widget"), self_start(), children![
+        widget::label("Bullet recall speed"),
+        widget::analog_slider(RecallSpeedLabel, lower_recall_speed, raise_recall_speed),
+    ])
+}
+
+fn lower_recall_speed(_: On<Pointer<Click>>, mut settings: ResMut<RecallSettings>) {
+    settings.speed = (settings.speed - RECALL_SPEED_STEP).max(MIN_RECALL_SPEED);
+}
+
+fn raise_recall_speed(_: On<Pointer<Click>>, mut settings: ResMut<RecallSettings>) {
+    settings.speed = (settings.speed + RECALL_SPEED_STEP).min(MAX_RECALL_SPEED);
+}
+
+fn update_recall_speed_label(
+    settings: Res<RecallSettings>,
+    mut label: Single<&mut Text, With<RecallSpeedLabel>>,
+) {
+    label.0 = format!("{:3.0}", settings.speed);
+}
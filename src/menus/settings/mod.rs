@@ -1,12 +1,14 @@
 use bevy::prelude::*;
 
 mod audio_settings;
+mod gameplay_settings;
 mod video_settings;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins((
         settings_menu::plugin,
         audio_settings::plugin,
+        gameplay_settings::plugin,
         video_settings::plugin,
     ));
 }
@@ -14,6 +16,7 @@ pub(super) fn plugin(app: &mut App) {
 mod settings_menu {
     use crate::{
         camera::MainCamera,
+        game::daily::RunDailyChallenge,
         menus::{Menu, pop_menu_on_click, to_menu_on_click},
         screens::Screen,
         theme::{widget, widget::settings_list},
@@ -110,23 +113,41 @@ mod settings_menu {
         }
     }
 
-    fn spawn_settings_menu(mut commands: Commands) {
+    fn spawn_settings_menu(mut commands: Commands, daily: Res<RunDailyChallenge>) {
         commands.spawn((
             widget::ui_root("Settings Menu"),
             GlobalZIndex(3),
             DespawnOnExit(Menu::Settings),
             children![
                 widget::h2("Settings"),
-                grid(),
+                grid(daily.0.is_some()),
                 widget::button("Back", pop_menu_on_click),
             ],
         ));
     }
 
-    fn grid() -> impl Bundle {
-        (settings_list(), children![
-            widget::button("Audio Settings", to_menu_on_click(Menu::AudioSettings)),
-            widget::button("Video Settings", to_menu_on_click(Menu::VideoSettings)),
-        ])
+    /// Gameplay settings are locked during a daily challenge run.
+    fn grid(is_daily_run: bool) -> impl Bundle {
+        (
+            settings_list(),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                if is_daily_run {
+                    parent.spawn(widget::label("Gameplay settings locked for the daily"));
+                } else {
+                    parent.spawn(widget::button(
+                        "Gameplay Settings",
+                        to_menu_on_click(Menu::GameplaySettings),
+                    ));
+                }
+                parent.spawn(widget::button(
+                    "Audio Settings",
+                    to_menu_on_click(Menu::AudioSettings),
+                ));
+                parent.spawn(widget::button(
+                    "Video Settings",
+                    to_menu_on_click(Menu::VideoSettings),
+                ));
+            })),
+        )
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     camera::MainCamera,
-    game::visuals::VisualIntensity,
+    game::{minimap::MinimapSettings, visuals::VisualIntensity},
     menus::{Menu, MenuAction, action_just_pressed, pop_menu_on_click},
     theme::widget::{self, ValueChange, self_end, self_start, settings_list},
 };
@@ -30,6 +30,7 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         (
             update_intensity_ui_value,
+            update_minimap_size_ui_value,
             apply_bloom_enabled.run_if(resource_changed::<BloomEnabled>),
             apply_pixel_perfect_enabled.run_if(resource_changed::<PixelPerfectEnabled>),
         )
@@ -47,6 +48,9 @@ struct FullscreenToggleCheckbox;
 #[derive(Component, Default)]
 struct BloomToggleCheckbox;
 
+#[derive(Component, Default)]
+struct MinimapToggleCheckbox;
+
 fn apply_fullscreen(
     fullscreen: Res<IsFullscreen>,
     mut window: Single<&mut Window, With<PrimaryWindow>>,
@@ -135,6 +139,7 @@ fn spawn_video_settings_menu(
     fullscreen: Res<IsFullscreen>,
     bloom: Res<BloomEnabled>,
     pixel_perfect: Res<PixelPerfectEnabled>,
+    minimap: Res<MinimapSettings>,
 ) {
     commands.spawn((
         widget::ui_root("Video Settings Menu"),
@@ -142,7 +147,7 @@ fn spawn_video_settings_menu(
         DespawnOnExit(Menu::VideoSettings),
         children![
             widget::h2("Video Settings"),
-            video_settings_grid(fullscreen.0, bloom.0, pixel_perfect.0),
+            video_settings_grid(fullscreen.0, bloom.0, pixel_perfect.0, minimap.enabled),
             widget::button("Back", pop_menu_on_click),
         ],
     ));
@@ -152,15 +157,50 @@ fn video_settings_grid(
     is_fullscreen: bool,
     has_bloom: bool,
     is_pixel_perfect: bool,
+    has_minimap: bool,
 ) -> impl Bundle {
     (settings_list(), children![
         fullscreen_toggle_widget(is_fullscreen),
         image_sampler_widget(is_pixel_perfect),
         bloom_toggle_widget(has_bloom),
         visual_intensity_widget(),
+        minimap_toggle_widget(has_minimap),
+        minimap_size_widget(),
     ])
 }
 
+fn minimap_toggle_widget(has_minimap: bool) -> impl Bundle {
+    (Name::new("Minimap Toggle"), self_start(), children![(
+        widget::checkbox(
+            MinimapToggleCheckbox,
+            "Minimap? ",
+            has_minimap,
+            |trigger: On<ValueChange<bool>>, mut minimap: ResMut<MinimapSettings>| {
+                minimap.enabled = trigger.value;
+            }
+        ),
+    ),])
+}
+
+fn minimap_size_widget() -> impl Bundle {
+    (
+        Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            column_gap: px(24),
+            ..default()
+        },
+        children![
+            (widget::label("Minimap Size"), self_end(),),
+            widget::analog_slider(
+                MinimapSizeUiValue,
+                decrease_minimap_size,
+                increase_minimap_size
+            ),
+        ],
+    )
+}
+
 fn visual_intensity_widget() -> impl Bundle {
     (
         Node {
@@ -292,3 +332,23 @@ fn update_intensity_ui_value(
     let percent = 100.0 * intensity.0;
     ui_value.0 = format!("{percent:3.0}%");
 }
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+struct MinimapSizeUiValue;
+
+fn increase_minimap_size(_: On<Pointer<Click>>, mut minimap: ResMut<MinimapSettings>) {
+    minimap.size = (minimap.size + MinimapSettings::SIZE_STEP)
+        .clamp(MinimapSettings::MIN_SIZE, MinimapSettings::MAX_SIZE);
+}
+
+fn decrease_minimap_size(_: On<Pointer<Click>>, mut minimap: ResMut<MinimapSettings>) {
+    minimap.size = (minimap.size - MinimapSettings::SIZE_STEP)
+        .clamp(MinimapSettings::MIN_SIZE, MinimapSettings::MAX_SIZE);
+}
+
+fn update_minimap_size_ui_value(
+    minimap: Res<MinimapSettings>,
+    mut ui_value: Single<&mut Text, With<MinimapSizeUiValue>>,
+) {
+    ui_value.0 = format!("{:.0}px", minimap.size);
+}
@@ -1,8 +1,13 @@
 use crate::{
     Paused,
     game::{
-        health::HealthValueUi, level::spawn_level, score::ScoreValueUi,
+        bullet::{BulletTypeValueUi, RecallValueUi},
+        game_mode::ObjectiveValueUi,
+        health::HealthValueUi,
+        level::spawn_level,
+        score::ScoreValueUi,
         survival_timer::TimeSurvivedValueUi,
+        waves::{NextWaveValueUi, RemainingEnemiesValueUi},
     },
     input::MenuAction,
     menus::Menu,
@@ -60,12 +65,51 @@ fn spawn_ui(mut commands: Commands) {
                 TextColor(Color::WHITE),
                 children![(
                     Name::new("Health value"),
-                    TextSpan::new("100"),
+                    TextSpan::new("100/100"),
                     HealthValueUi,
                     TextFont::from_font_size(24.0),
                     TextColor(Color::WHITE)
                 )],
             ),
+            (
+                Name::new("BulletType text"),
+                Text::new("Bullet: "),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+                children![(
+                    Name::new("BulletType value"),
+                    TextSpan::new("Standard"),
+                    BulletTypeValueUi,
+                    TextFont::from_font_size(24.0),
+                    TextColor(Color::WHITE)
+                )],
+            ),
+            (
+                Name::new("Recall text"),
+                Text::new("Recall: "),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+                children![(
+                    Name::new("Recall value"),
+                    TextSpan::new("Ready"),
+                    RecallValueUi,
+                    TextFont::from_font_size(24.0),
+                    TextColor(Color::WHITE)
+                )],
+            ),
+            (
+                Name::new("Objective text"),
+                Text::new("Objective: "),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+                children![(
+                    Name::new("Objective value"),
+                    TextSpan::new("Survive"),
+                    ObjectiveValueUi,
+                    TextFont::from_font_size(24.0),
+                    TextColor(Color::WHITE)
+                )],
+            ),
             (
                 Name::new("SurvivalTimer text"),
                 Text::new("Time Survived: "),
@@ -79,6 +123,32 @@ fn spawn_ui(mut commands: Commands) {
                     TextColor(Color::WHITE)
                 )],
             ),
+            (
+                Name::new("NextWave text"),
+                Text::new("Next Wave: "),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+                children![(
+                    Name::new("NextWave value"),
+                    TextSpan::new(""),
+                    NextWaveValueUi,
+                    TextFont::from_font_size(24.0),
+                    TextColor(Color::WHITE)
+                )],
+            ),
+            (
+                Name::new("RemainingEnemies text"),
+                Text::new("Enemies: "),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+                children![(
+                    Name::new("RemainingEnemies value"),
+                    TextSpan::new("0"),
+                    RemainingEnemiesValueUi,
+                    TextFont::from_font_size(24.0),
+                    TextColor(Color::WHITE)
+                )],
+            ),
             (
                 Name::new("Score text"),
                 Text::new("Score: "),
@@ -14,3 +14,6 @@ pub const BUTTON_BACKGROUND: Color = Color::srgb(0.275, 0.400, 0.750);
 pub const BUTTON_HOVERED_BACKGROUND: Color = Color::srgb(0.384, 0.600, 0.820);
 /// #3d4999
 pub const BUTTON_PRESSED_BACKGROUND: Color = Color::srgb(0.239, 0.286, 0.600);
+
+/// #22264b, slightly transparent
+pub const TOAST_BACKGROUND: Color = Color::srgba(0.133, 0.149, 0.294, 0.9);
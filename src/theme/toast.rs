@@ -0,0 +1,50 @@
+//! Short notifications stacked in the top right corner, see
+//! [`widget::toast`](super::widget::toast).
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, update_toasts);
+}
+
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+const TOAST_MARGIN: f32 = 16.0;
+/// Vertical distance between stacked toasts.
+const TOAST_SPACING: f32 = 88.0;
+
+/// Despawned once its timer finishes.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Toast(Timer);
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self(Timer::new(TOAST_DURATION, TimerMode::Once))
+    }
+}
+
+fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut toasts: Query<(Entity, &mut Toast, &mut Node)>,
+) {
+    let mut stack = Vec::new();
+    for (entity, mut toast, node) in &mut toasts {
+        toast.0.tick(time.delta());
+        if toast.0.is_finished() {
+            commands.entity(entity).despawn();
+        } else {
+            stack.push((toast.0.elapsed(), node));
+        }
+    }
+
+    // Newest on top
+    stack.sort_by_key(|(elapsed, _)| *elapsed);
+    for (index, (_, mut node)) in stack.into_iter().enumerate() {
+        let top = px(TOAST_MARGIN + index as f32 * TOAST_SPACING);
+        if node.top != top {
+            node.top = top;
+        }
+    }
+}
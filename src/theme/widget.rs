@@ -3,6 +3,7 @@
 use crate::theme::{
     interaction::{InteractionPalette, checkbox::CheckedDefault},
     palette::*,
+    toast::Toast,
 };
 pub use bevy::ui_widgets::*;
 use bevy::{
@@ -74,6 +75,42 @@ pub fn label(text: impl Into<String>) -> impl Bundle {
     )
 }
 
+/// A notification with a title and text in the top right corner, disappearing on its own.
+pub fn toast(title: impl Into<String>, text: impl Into<String>) -> impl Bundle {
+    (
+        Name::new("Toast"),
+        Toast::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(16),
+            right: px(16),
+            width: px(360),
+            padding: UiRect::all(px(12)),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(4),
+            ..default()
+        },
+        BackgroundColor(TOAST_BACKGROUND),
+        BorderRadius::all(px(8)),
+        GlobalZIndex(10),
+        Pickable::IGNORE,
+        children![
+            (
+                Name::new("Toast Title"),
+                Text(title.into()),
+                TextFont::from_font_size(24.0),
+                TextColor(HEADER_TEXT),
+            ),
+            (
+                Name::new("Toast Text"),
+                Text(text.into()),
+                TextFont::from_font_size(18.0),
+                TextColor(LABEL_TEXT),
+            ),
+        ],
+    )
+}
+
 /// A large rounded button with text and an action defined as an [`Observer`].
 pub fn button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
 where